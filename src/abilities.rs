@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content;
+use crate::locale::Locale;
+use crate::status::StatusEffect;
+
+/// Who an ability's effects land on.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AbilityTarget {
+    Enemy,
+    Caster,
+}
+
+/// One building block of an ability. Effects are applied in order, so a
+/// `Drain` placed after a `Damage` heals off that hit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AbilityEffect {
+    /// Hit for `percent`% of a normal attack; `pierce` ignores defense and guard.
+    Damage { percent: u32, pierce: bool },
+    /// Heal the caster for `percent`% of the damage dealt so far.
+    Drain { percent: u32 },
+    /// Heal the caster for `percent`% of their max HP.
+    Heal { percent: u32 },
+    /// The next incoming attack misses entirely.
+    Evade,
+    /// Permanently lower the target's DEF.
+    Sunder { amount: u32 },
+    /// Attach a timed status effect to the target.
+    ApplyStatus(StatusEffect),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ability {
+    pub id: String,
+    /// Turns before the ability can be used again (1 = every turn).
+    pub cooldown: u32,
+    pub target: AbilityTarget,
+    pub effects: Vec<AbilityEffect>,
+}
+
+/// An ability equipped by a combatant along with its remaining cooldown.
+#[derive(Clone, Debug)]
+pub struct AbilitySlot {
+    pub ability: Ability,
+    pub cooldown_left: u32,
+}
+
+impl AbilitySlot {
+    pub fn ready(&self) -> bool {
+        self.cooldown_left == 0
+    }
+}
+
+impl Ability {
+    pub fn by_id(id: &str) -> Option<Self> {
+        content::get()
+            .abilities
+            .iter()
+            .find(|a| a.id == id)
+            .cloned()
+    }
+
+    pub fn name(&self, locale: Locale) -> String {
+        Self::name_of(&self.id, locale)
+    }
+
+    /// The name of the ability with id `id`, for events that carry only
+    /// the id.
+    pub fn name_of(id: &str, locale: Locale) -> String {
+        locale.t(&content::text_key("ability", id, "name"))
+    }
+
+    pub fn description(&self, locale: Locale) -> String {
+        locale.t(&content::text_key("ability", &self.id, "description"))
+    }
+}
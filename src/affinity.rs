@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+use crate::locale::Locale;
+
+/// Damage multiplier (percent) of a super effective hit.
+pub const SUPER_EFFECTIVE_PERCENT: u32 = 125;
+
+/// Damage multiplier (percent) of a resisted hit.
+pub const RESISTED_PERCENT: u32 = 80;
+
+/// Elemental affinity of a fighter. Every hit carries the attacker's
+/// affinity and is scaled by how it fares against the defender's.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Affinity {
+    /// No strengths or weaknesses.
+    #[default]
+    Neutral,
+    Thunder,
+    Frost,
+    Fire,
+    Shadow,
+    Order,
+    Life,
+    Death,
+    Wild,
+}
+
+/// How a hit of one affinity fares against another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effectiveness {
+    SuperEffective,
+    Normal,
+    Resisted,
+}
+
+impl Effectiveness {
+    /// Damage multiplier, in percent.
+    pub fn percent(self) -> u32 {
+        match self {
+            Effectiveness::SuperEffective => SUPER_EFFECTIVE_PERCENT,
+            Effectiveness::Normal => 100,
+            Effectiveness::Resisted => RESISTED_PERCENT,
+        }
+    }
+}
+
+impl Affinity {
+    pub fn label(self, locale: Locale) -> String {
+        locale.t(match self {
+            Affinity::Neutral => "affinity.neutral",
+            Affinity::Thunder => "affinity.thunder",
+            Affinity::Frost => "affinity.frost",
+            Affinity::Fire => "affinity.fire",
+            Affinity::Shadow => "affinity.shadow",
+            Affinity::Order => "affinity.order",
+            Affinity::Life => "affinity.life",
+            Affinity::Death => "affinity.death",
+            Affinity::Wild => "affinity.wild",
+        })
+    }
+
+    /// Affinities this one hits super effectively.
+    pub fn strong_against(self) -> &'static [Affinity] {
+        use Affinity::*;
+        match self {
+            Neutral => &[],
+            // Mjolnir fells giants of ice and fire alike
+            Thunder => &[Frost, Fire],
+            Frost => &[Life, Order],
+            Fire => &[Frost, Shadow],
+            Shadow => &[Order, Thunder],
+            Order => &[Wild, Death],
+            Life => &[Death, Wild],
+            Death => &[Thunder, Frost],
+            Wild => &[Shadow, Fire],
+        }
+    }
+
+    /// The table: super effective when `self` is strong against the
+    /// defender; resisted by the same affinity or one strong against it.
+    pub fn against(self, defender: Affinity) -> Effectiveness {
+        if self.strong_against().contains(&defender) {
+            Effectiveness::SuperEffective
+        } else if self != Affinity::Neutral
+            && (self == defender || defender.strong_against().contains(&self))
+        {
+            Effectiveness::Resisted
+        } else {
+            Effectiveness::Normal
+        }
+    }
+
+    /// Net edge of `self` over `other` counting both directions of attack:
+    /// positive is a favourable matchup, negative an unfavourable one.
+    pub fn advantage(self, other: Affinity) -> i32 {
+        self.against(other).percent() as i32 - other.against(self).percent() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Affinity::*;
+    use Effectiveness::*;
+
+    const ALL: [Affinity; 9] = [Neutral, Thunder, Frost, Fire, Shadow, Order, Life, Death, Wild];
+
+    #[test]
+    fn table() {
+        let cases = [
+            (Thunder, Frost, SuperEffective),
+            (Thunder, Fire, SuperEffective),
+            (Wild, Shadow, SuperEffective),
+            (Frost, Thunder, Resisted),
+            (Fire, Wild, Resisted),
+            (Death, Death, Resisted),
+            (Thunder, Life, Normal),
+            (Fire, Order, Normal),
+            (Neutral, Thunder, Normal),
+            (Shadow, Neutral, Normal),
+            (Neutral, Neutral, Normal),
+        ];
+        for (attacker, defender, expected) in cases {
+            assert_eq!(
+                attacker.against(defender),
+                expected,
+                "{:?} against {:?}",
+                attacker,
+                defender
+            );
+        }
+    }
+
+    #[test]
+    fn matchups_mirror() {
+        for a in ALL {
+            for b in ALL {
+                // A strength is the defender's resistance the other way round
+                if a.against(b) == SuperEffective {
+                    assert_eq!(b.against(a), Resisted, "{:?} against {:?}", b, a);
+                }
+                assert_eq!(a.advantage(b), -b.advantage(a), "{:?} and {:?}", a, b);
+            }
+        }
+    }
+}
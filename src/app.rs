@@ -3,11 +3,21 @@ use leptos_router::components::*;
 use leptos_router::path;
 use serde::{Deserialize, Serialize};
 
+use crate::campaign::CampaignRun;
+use crate::components::language_select::LanguageSelect;
 use crate::components::wallet_button::WalletButton;
+use crate::creator;
+use crate::equipment::Loadout;
+use crate::game_state::{Action, CharacterTemplate, MAX_PARTY};
+use crate::items::{self, Inventory};
+use crate::locale::Locale;
 use crate::pages::{
-    character_select::CharacterSelectPage, edit_profile::EditProfilePage,
-    game_session::GameSessionPage, preview::PreviewPage, start::StartPage,
+    campaign::CampaignPage, character_select::CharacterSelectPage, creator::CreatorPage,
+    edit_profile::EditProfilePage, game_session::GameSessionPage, preview::PreviewPage,
+    pvp::PvpPage, replays::ReplaysPage, rivals::RivalsPage, start::StartPage,
 };
+use crate::progression;
+use crate::rival::Rival;
 use crate::wallet;
 
 // Global wallet state
@@ -21,6 +31,75 @@ pub struct WalletState {
 #[derive(Clone, Debug, Default)]
 pub struct SelectedCharacter {
     pub index: Option<usize>,
+    /// Teammates fighting alongside the selected character in single
+    /// battles (indices as in `CustomCharacters::roster()`).
+    pub party: Vec<usize>,
+}
+
+impl SelectedCharacter {
+    /// Make `index` the lead, keeping the rest of the party.
+    pub fn select(&mut self, index: usize) {
+        self.index = Some(index);
+        self.party.retain(|&i| i != index);
+    }
+
+    /// Add or remove a teammate. The party is capped at MAX_PARTY
+    /// including the lead.
+    pub fn toggle_teammate(&mut self, index: usize) {
+        if let Some(pos) = self.party.iter().position(|&i| i == index) {
+            self.party.remove(pos);
+        } else if Some(index) != self.index && self.party.len() + 1 < MAX_PARTY {
+            self.party.push(index);
+        }
+    }
+
+    /// Lead first, then teammates.
+    pub fn lineup(&self) -> Vec<usize> {
+        self.index.into_iter().chain(self.party.iter().copied()).collect()
+    }
+}
+
+// Selected opponent — index into EnemyTemplate::all()
+#[derive(Clone, Debug, Default)]
+pub struct SelectedEnemy {
+    pub index: usize,
+}
+
+// Campaign in progress, if the player is on a run rather than a single fight
+#[derive(Clone, Debug, Default)]
+pub struct CampaignState {
+    pub run: Option<CampaignRun>,
+}
+
+// Local two-player battle: the second player's character, index into
+// CharacterTemplate::all() (None when fighting the AI)
+#[derive(Clone, Debug, Default)]
+pub struct HotSeatState {
+    pub rival: Option<usize>,
+}
+
+// Another wallet's on-chain snapshot being fought (async PvP)
+#[derive(Clone, Debug, Default)]
+pub struct RivalState {
+    pub rival: Option<Rival>,
+}
+
+// Optional clock on a person's turns, so nobody can stall a fight
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TurnClock {
+    /// Seconds per turn; 0 turns the clock off.
+    pub seconds: u32,
+    /// Played for whoever lets the clock run out.
+    pub on_timeout: Action,
+}
+
+impl Default for TurnClock {
+    fn default() -> Self {
+        Self {
+            seconds: 0,
+            on_timeout: Action::Defend,
+        }
+    }
 }
 
 // Collection context — stores a created collection pubkey for minting
@@ -29,23 +108,136 @@ pub struct CollectionState {
     pub pubkey: Option<solana_pubkey::Pubkey>,
 }
 
+/// Index of the first custom hero. Gods keep the indices below it however
+/// many the content adds, so selections and mints stay stable.
+pub const CUSTOM_INDEX_BASE: usize = 1000;
+
+// Heroes made in the character creator, after the content's gods. Heroes
+// that fail validation (say, edited in localStorage) are skipped but keep
+// their place so later indices don't shift.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CustomCharacters {
+    pub characters: Vec<CharacterTemplate>,
+}
+
+impl CustomCharacters {
+    /// Every playable character with the index selections and mints use.
+    pub fn roster(&self) -> Vec<(usize, CharacterTemplate)> {
+        CharacterTemplate::all()
+            .into_iter()
+            .enumerate()
+            .chain(
+                self.characters
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .filter(|(_, c)| creator::is_valid(c))
+                    .map(|(i, c)| (CUSTOM_INDEX_BASE + i, c)),
+            )
+            .collect()
+    }
+
+    /// The god or custom hero at `index`.
+    pub fn template(&self, index: usize) -> Option<CharacterTemplate> {
+        match index.checked_sub(CUSTOM_INDEX_BASE) {
+            Some(i) => self
+                .characters
+                .get(i)
+                .filter(|c| creator::is_valid(c))
+                .cloned(),
+            None => CharacterTemplate::all().get(index).cloned(),
+        }
+    }
+
+    /// Add a hero built by the creator. Returns its index, or an error if
+    /// it fails validation or its name is taken.
+    pub fn add(&mut self, hero: CharacterTemplate, locale: Locale) -> Result<usize, String> {
+        creator::validate(&hero, locale)?;
+        let taken = self
+            .roster()
+            .iter()
+            .any(|(_, c)| c.name.eq_ignore_ascii_case(&hero.name));
+        if taken {
+            return Err(locale.format(
+                "creator.error.name_taken",
+                &[("name", hero.name.as_str().into())],
+            ));
+        }
+        self.characters.push(hero);
+        Ok(CUSTOM_INDEX_BASE + self.characters.len() - 1)
+    }
+}
+
 // Track minted characters for the profile page
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct MintedCharacters {
     pub characters: Vec<MintedCharacterInfo>,
 }
 
+impl MintedCharacters {
+    /// Progress of the minted character at `index` (see
+    /// `CustomCharacters::roster()`).
+    pub fn get(&self, index: usize) -> Option<&MintedCharacterInfo> {
+        self.characters.iter().find(|c| c.index == index)
+    }
+
+    /// Level of the character at `index` (1 if it hasn't been minted).
+    pub fn level_of(&self, index: usize) -> u32 {
+        self.get(index).map_or(1, |c| c.level())
+    }
+
+    /// Equipment worn by the character at `index` (none if it hasn't been
+    /// minted).
+    pub fn loadout_of(&self, index: usize) -> Loadout {
+        self.get(index).map(|c| c.loadout.clone()).unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MintedCharacterInfo {
     pub name: String,
     pub index: usize,
     pub tx_signature: String,
+    /// Experience earned in battle.
+    #[serde(default)]
+    pub xp: u32,
+    #[serde(default)]
+    pub loadout: Loadout,
+}
+
+impl MintedCharacterInfo {
+    pub fn level(&self) -> u32 {
+        progression::level_for_xp(self.xp)
+    }
 }
 
 #[component]
 pub fn App() -> impl IntoView {
     let wallet = RwSignal::new(WalletState::default());
     let selected_char = RwSignal::new(SelectedCharacter::default());
+    let selected_enemy = RwSignal::new(SelectedEnemy::default());
+    let campaign = RwSignal::new(CampaignState::default());
+    let hot_seat = RwSignal::new(HotSeatState::default());
+    let rival = RwSignal::new(RivalState::default());
+
+    // Saved language, else the browser's, else English
+    let initial_locale = wallet::load_from_storage("mojo_locale")
+        .or_else(|| web_sys::window().and_then(|w| w.navigator().language()))
+        .and_then(|code| Locale::from_code(&code))
+        .unwrap_or_default();
+    let locale = RwSignal::new(initial_locale);
+
+    // Persist the language and keep <html lang> in step with it
+    Effect::new(move || {
+        let l = locale.get();
+        wallet::save_to_storage("mojo_locale", l.code());
+        if let Some(html) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.document_element())
+        {
+            let _ = html.set_attribute("lang", l.code());
+        }
+    });
 
     // Load persisted collection pubkey from localStorage
     let initial_collection = {
@@ -78,6 +270,34 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    // Load the turn clock settings
+    let initial_clock = wallet::load_from_storage("mojo_turn_clock")
+        .and_then(|s| serde_json::from_str::<TurnClock>(&s).ok())
+        .unwrap_or_default();
+    let turn_clock = RwSignal::new(initial_clock);
+
+    // Persist the turn clock settings whenever they change
+    Effect::new(move || {
+        let t = turn_clock.get();
+        if let Ok(json) = serde_json::to_string(&t) {
+            wallet::save_to_storage("mojo_turn_clock", &json);
+        }
+    });
+
+    // Load custom heroes made in the creator
+    let initial_custom = wallet::load_from_storage("mojo_custom_chars")
+        .and_then(|s| serde_json::from_str::<CustomCharacters>(&s).ok())
+        .unwrap_or_default();
+    let custom = RwSignal::new(initial_custom);
+
+    // Persist custom heroes whenever they change
+    Effect::new(move || {
+        let c = custom.get();
+        if let Ok(json) = serde_json::to_string(&c) {
+            wallet::save_to_storage("mojo_custom_chars", &json);
+        }
+    });
+
     // Persist collection pubkey whenever it changes
     Effect::new(move || {
         let c = collection.get();
@@ -86,10 +306,34 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    // Consumables belong to the connected wallet; swap them in on connect
+    let inventory = RwSignal::new(Inventory::default());
+    Effect::new(move || {
+        if let Some(pk) = wallet.get().pubkey {
+            inventory.set(items::load(&pk));
+        }
+    });
+
+    // Persist the wallet's inventory whenever it changes
+    Effect::new(move || {
+        let inv = inventory.get();
+        if let Some(pk) = wallet.get_untracked().pubkey {
+            items::save(&pk, &inv);
+        }
+    });
+
     provide_context(wallet);
+    provide_context(locale);
     provide_context(selected_char);
+    provide_context(selected_enemy);
+    provide_context(campaign);
+    provide_context(hot_seat);
+    provide_context(rival);
+    provide_context(turn_clock);
     provide_context(collection);
     provide_context(minted);
+    provide_context(custom);
+    provide_context(inventory);
 
     view! {
         <Router>
@@ -97,19 +341,30 @@ pub fn App() -> impl IntoView {
                 <header class="header">
                     <span class="header-title">"MOJO SDK DEMO"</span>
                     <nav class="nav-links">
-                        <a href="/">"Home"</a>
-                        <a href="/select">"Characters"</a>
-                        <a href="/battle">"Battle"</a>
-                        <a href="/profile">"Profile"</a>
+                        <a href="/">{move || locale.get().t("nav.home")}</a>
+                        <a href="/select">{move || locale.get().t("nav.characters")}</a>
+                        <a href="/create">{move || locale.get().t("nav.create")}</a>
+                        <a href="/battle">{move || locale.get().t("nav.battle")}</a>
+                        <a href="/campaign">{move || locale.get().t("nav.campaign")}</a>
+                        <a href="/pvp">{move || locale.get().t("nav.pvp")}</a>
+                        <a href="/rivals">{move || locale.get().t("nav.rivals")}</a>
+                        <a href="/replays">{move || locale.get().t("nav.replays")}</a>
+                        <a href="/profile">{move || locale.get().t("nav.profile")}</a>
                     </nav>
+                    <LanguageSelect />
                     <WalletButton />
                 </header>
-                <Routes fallback=|| view! { <p>"Page not found"</p> }>
+                <Routes fallback=move || view! { <p>{move || locale.get().t("app.not_found")}</p> }>
                     <Route path=path!("/") view=StartPage />
                     <Route path=path!("/select") view=CharacterSelectPage />
+                    <Route path=path!("/create") view=CreatorPage />
                     <Route path=path!("/battle") view=GameSessionPage />
+                    <Route path=path!("/campaign") view=CampaignPage />
+                    <Route path=path!("/pvp") view=PvpPage />
+                    <Route path=path!("/rivals") view=RivalsPage />
                     <Route path=path!("/profile") view=EditProfilePage />
                     <Route path=path!("/preview") view=PreviewPage />
+                    <Route path=path!("/replays") view=ReplaysPage />
                 </Routes>
             </div>
         </Router>
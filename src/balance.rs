@@ -0,0 +1,177 @@
+//! Headless balance simulator. Plays every character template against
+//! every enemy with a policy-driven player and checks each character's
+//! overall win rate against its band in `BANDS`.
+//!
+//! `cargo test` runs a reduced set of seeded fights. The full report is an
+//! ignored test:
+//!
+//!     BALANCE_FIGHTS=1000 BALANCE_POLICY=aggressive cargo test --release balance::report -- --ignored --nocapture
+
+use crate::enemy_ai;
+use crate::game_state::{
+    Action, BattleResult, BattleState, CharacterTemplate, EnemyBehaviour, EnemyTemplate, Turn,
+};
+use crate::locale::Locale;
+
+/// Fights stopped after this many actions count as losses.
+const MAX_ACTIONS: usize = 500;
+
+/// Seeded fights per matchup in the regression check.
+const CHECK_FIGHTS: u32 = 100;
+
+/// Accepted overall win rate (percent, over every enemy) per character
+/// with the aggressive policy. Re-baseline these when a balance change is
+/// intended.
+const BANDS: &[(&str, f64, f64)] = &[
+    ("Freya", 95.0, 100.0),
+    ("Odin", 95.0, 100.0),
+    ("Thor", 84.0, 94.0),
+    ("Loki", 84.0, 94.0),
+    ("Hel", 91.0, 100.0),
+    ("Tyr", 93.0, 100.0),
+];
+
+/// Totals for one character against one enemy.
+#[derive(Default)]
+struct Tally {
+    fights: u32,
+    wins: u32,
+    turns: u32,
+    score: u64,
+    hp_left: i64,
+}
+
+impl Tally {
+    fn add(&mut self, other: &Tally) {
+        self.fights += other.fights;
+        self.wins += other.wins;
+        self.turns += other.turns;
+        self.score += other.score;
+        self.hp_left += other.hp_left;
+    }
+
+    fn win_rate(&self) -> f64 {
+        100.0 * self.wins as f64 / self.fights.max(1) as f64
+    }
+
+    fn row(&self, label: &str) -> String {
+        let n = self.fights.max(1) as f64;
+        format!(
+            "{:<14} {:>6.1}% {:>7.1} {:>8.1} {:>7.1}",
+            label,
+            self.win_rate(),
+            self.turns as f64 / n,
+            self.score as f64 / n,
+            self.hp_left as f64 / n,
+        )
+    }
+}
+
+/// One fight from `seed`, the character played by `policy`.
+fn fight(
+    character: &CharacterTemplate,
+    enemy: &EnemyTemplate,
+    policy: EnemyBehaviour,
+    seed: u64,
+) -> Tally {
+    let player = enemy_ai::for_behaviour(policy);
+    let mut battle = BattleState::team_battle(
+        std::slice::from_ref(character),
+        std::slice::from_ref(enemy),
+        seed,
+    );
+    while battle.result.is_none() && battle.actions.len() < MAX_ACTIONS {
+        if battle.ai_turn().is_some() {
+            continue;
+        }
+        if !battle.act(player.choose(&battle, Turn::Player)) {
+            battle.act(Action::Attack);
+        }
+    }
+    Tally {
+        fights: 1,
+        wins: (battle.result == Some(BattleResult::Victory)) as u32,
+        turns: battle
+            .actions
+            .iter()
+            .filter(|(side, _)| *side == Turn::Player)
+            .count() as u32,
+        score: battle.score as u64,
+        hp_left: battle.player().hp.max(0) as i64,
+    }
+}
+
+fn parse_policy(name: &str) -> Result<EnemyBehaviour, String> {
+    match name {
+        "aggressive" => Ok(EnemyBehaviour::Aggressive),
+        "cautious" => Ok(EnemyBehaviour::Cautious),
+        "cunning" => Ok(EnemyBehaviour::Cunning),
+        "tactician" => Ok(EnemyBehaviour::Tactician),
+        _ => Err(format!("Unknown policy: {}", name)),
+    }
+}
+
+/// Play `fights` seeded fights per matchup. Returns the report table and
+/// every character whose overall win rate left its band.
+fn simulate(fights: u32, policy: EnemyBehaviour) -> (String, Vec<String>) {
+    let mut report = format!(
+        "{} fights per matchup, player policy: {}\n",
+        fights,
+        policy.label(Locale::En)
+    );
+    let mut drifted = Vec::new();
+    for character in CharacterTemplate::all() {
+        report.push_str(&format!(
+            "\n{:<14} {:>7} {:>7} {:>8} {:>7}\n",
+            character.name.to_uppercase(),
+            "WIN",
+            "TURNS",
+            "SCORE",
+            "HP LEFT"
+        ));
+        let mut overall = Tally::default();
+        for enemy in EnemyTemplate::all() {
+            let mut tally = Tally::default();
+            for seed in 0..fights as u64 {
+                tally.add(&fight(&character, &enemy, policy, seed));
+            }
+            report.push_str(&format!("{}\n", tally.row(&enemy.name)));
+            overall.add(&tally);
+        }
+        report.push_str(&format!("{}\n", overall.row("overall")));
+
+        if let Some(&(_, min, max)) = BANDS.iter().find(|(name, ..)| *name == character.name) {
+            let rate = overall.win_rate();
+            if rate < min || rate > max {
+                drifted.push(format!(
+                    "{} wins {:.1}%, outside {:.1}–{:.1}%",
+                    character.name, rate, min, max
+                ));
+            }
+        }
+    }
+    (report, drifted)
+}
+
+#[test]
+fn win_rates_stay_in_bands() {
+    let (report, drifted) = simulate(CHECK_FIGHTS, EnemyBehaviour::Aggressive);
+    assert!(drifted.is_empty(), "{}\nDRIFT: {}", report, drifted.join("\nDRIFT: "));
+}
+
+#[test]
+#[ignore = "full balance report; run with --ignored --nocapture"]
+fn report() {
+    let fights = std::env::var("BALANCE_FIGHTS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(1000);
+    let policy = std::env::var("BALANCE_POLICY")
+        .map_or(Ok(EnemyBehaviour::Aggressive), |name| parse_policy(&name))
+        .unwrap();
+    let (report, drifted) = simulate(fights, policy);
+    println!("{}", report);
+    for line in &drifted {
+        println!("DRIFT: {}", line);
+    }
+}
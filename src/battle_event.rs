@@ -0,0 +1,559 @@
+use crate::abilities::Ability;
+use crate::affinity::Effectiveness;
+use crate::combo::ComboKind;
+use crate::game_state::{BattleResult, BattleState, LogEntry, LogKind, Turn};
+use crate::initiative::CombatantId;
+use crate::items::ItemKind;
+use crate::locale::{Arg, Locale};
+use crate::status::{StatusEffect, StatusKind};
+
+/// Something that happened in a battle. `BattleState::events` records them
+/// in order; the log, the hit animations and the score are all read off
+/// that stream.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BattleEvent {
+    /// The fight opened with `first` to move.
+    BattleStarted {
+        first: CombatantId,
+    },
+    TurnStarted {
+        id: CombatantId,
+    },
+    Defended {
+        id: CombatantId,
+    },
+    /// `id`'s attack finished a combo and hits harder.
+    Combo {
+        id: CombatantId,
+        combo: ComboKind,
+    },
+    /// `ability` is the ability's id.
+    AbilityUsed {
+        caster: CombatantId,
+        ability: String,
+    },
+    PotionDrunk {
+        id: CombatantId,
+        healed: u32,
+    },
+    /// An Attack Tonic raised `id`'s ATK to `atk`.
+    TonicDrunk {
+        id: CombatantId,
+        atk: u32,
+    },
+    SmokeBomb {
+        id: CombatantId,
+    },
+    /// `id` will evade the next attack.
+    Evading {
+        id: CombatantId,
+    },
+    /// A hit landed. `amount` is before any shield soaks it up; `ability`
+    /// is the id of the ability behind it (None for a basic attack).
+    DamageDealt {
+        source: CombatantId,
+        target: CombatantId,
+        amount: u32,
+        crit: bool,
+        ability: Option<String>,
+        effectiveness: Effectiveness,
+    },
+    /// A hit landed on a guarding fighter, who took `amount` and blocked
+    /// `avoided`.
+    Blocked {
+        source: CombatantId,
+        target: CombatantId,
+        amount: u32,
+        avoided: u32,
+        crit: bool,
+        effectiveness: Effectiveness,
+    },
+    /// An attack missed. `evaded` if the target was evading rather than
+    /// dodging the accuracy roll; `avoided` is what the hit would have
+    /// done before its rolls.
+    Missed {
+        source: CombatantId,
+        target: CombatantId,
+        evaded: bool,
+        avoided: u32,
+    },
+    ShieldAbsorbed {
+        target: CombatantId,
+        amount: u32,
+    },
+    /// A fighter hurt itself with its own ability.
+    Recoil {
+        id: CombatantId,
+        amount: u32,
+    },
+    /// `target`'s thorns reflected `amount` back onto `source`.
+    Thorns {
+        source: CombatantId,
+        target: CombatantId,
+        amount: u32,
+    },
+    Healed {
+        target: CombatantId,
+        amount: u32,
+        source: HealSource,
+    },
+    /// `target`'s DEF was cut to `def`.
+    Sundered {
+        target: CombatantId,
+        def: u32,
+    },
+    /// `on_hit` for an effect a fighter's attacks carry, otherwise it came
+    /// from an ability.
+    StatusApplied {
+        target: CombatantId,
+        effect: StatusEffect,
+        on_hit: bool,
+    },
+    StatusDamage {
+        target: CombatantId,
+        status: StatusKind,
+        amount: u32,
+    },
+    Stunned {
+        id: CombatantId,
+    },
+    StatusExpired {
+        id: CombatantId,
+        status: StatusKind,
+    },
+    Revived {
+        id: CombatantId,
+        hp: i32,
+    },
+    KnockedOut {
+        id: CombatantId,
+    },
+    /// A reserve took a fallen fighter's place.
+    SteppedIn {
+        id: CombatantId,
+    },
+    Swapped {
+        out: CombatantId,
+        incoming: CombatantId,
+    },
+    /// The boss entered its `phase`th phase (index into its template's
+    /// `phases`).
+    PhaseStarted {
+        id: CombatantId,
+        phase: usize,
+    },
+    /// A boss phase called `id` into the fight.
+    Summoned {
+        id: CombatantId,
+    },
+    BattleEnded {
+        result: BattleResult,
+    },
+    LootDropped {
+        item: ItemKind,
+    },
+}
+
+/// Where a heal came from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HealSource {
+    /// A Drain ability effect or a Lifesteal passive.
+    Drain,
+    /// A Heal ability effect, by ability id.
+    Ability(String),
+    /// The Regen status.
+    Status,
+    /// A Regeneration passive.
+    Regeneration,
+}
+
+impl BattleEvent {
+    /// Who a strike landed on and how, for the hit animation.
+    pub fn impact(&self) -> Option<(CombatantId, LogKind)> {
+        match *self {
+            BattleEvent::DamageDealt { target, crit, .. } => {
+                Some((target, if crit { LogKind::Crit } else { LogKind::Damage }))
+            }
+            BattleEvent::Blocked { target, crit, .. } => Some((
+                target,
+                if crit {
+                    LogKind::Crit
+                } else {
+                    blocked_kind(target)
+                },
+            )),
+            BattleEvent::Missed { target, .. } => Some((target, LogKind::Miss)),
+            _ => None,
+        }
+    }
+}
+
+/// A block is coloured from the first side's point of view.
+fn blocked_kind(target: CombatantId) -> LogKind {
+    match target.side {
+        Turn::Player => LogKind::Heal,
+        Turn::Enemy => LogKind::Info,
+    }
+}
+
+impl BattleState {
+    /// The battle log: every event so far written out in `locale`.
+    pub fn log(&self, locale: Locale) -> Vec<LogEntry> {
+        let mut log = Vec::new();
+        for event in &self.events {
+            self.describe(event, locale, &mut log);
+        }
+        log
+    }
+
+    /// The catalogue key for a line about `id`: the second-person form when
+    /// it's the player's own fighter.
+    fn key_for(&self, id: CombatantId, key: &str) -> String {
+        if self.is_you(id) {
+            format!("{}.you", key)
+        } else {
+            key.to_string()
+        }
+    }
+
+    /// Log lines for one event (none for some, e.g. `TurnStarted`).
+    fn describe(&self, event: &BattleEvent, locale: Locale, log: &mut Vec<LogEntry>) {
+        let mut line = |message: String, kind: LogKind| log.push(LogEntry { message, kind });
+        let name = |id: CombatantId| Arg::from(self.fighter_name(id, locale));
+        // A line about `id`, in the second person when it's the player
+        let about = |id: CombatantId, key: &str, args: &[(&str, Arg)]| {
+            let mut args = args.to_vec();
+            args.push(("name", name(id)));
+            locale.format(&self.key_for(id, key), &args)
+        };
+        match event {
+            BattleEvent::BattleStarted { first } => {
+                // Minions summoned later weren't there yet
+                let foes: Vec<String> = (0..self.starting_enemies().len())
+                    .map(|slot| self.fighter_name(CombatantId::new(Turn::Enemy, slot), locale))
+                    .collect();
+                line(
+                    locale.format(
+                        "log.battle_begins",
+                        &[
+                            ("names", foes.join(" & ").into()),
+                            ("count", foes.len().into()),
+                        ],
+                    ),
+                    LogKind::Info,
+                );
+                if first.side == Turn::Enemy {
+                    line(
+                        locale.format("log.strikes_first", &[("name", name(*first))]),
+                        LogKind::Info,
+                    );
+                }
+            }
+            BattleEvent::TurnStarted { .. } => {}
+            BattleEvent::Defended { id } => line(about(*id, "log.defend", &[]), LogKind::Info),
+            BattleEvent::Combo { combo, .. } => line(
+                locale.format(
+                    "log.combo",
+                    &[
+                        ("combo", combo.label(locale).into()),
+                        ("percent", combo.bonus_percent().into()),
+                    ],
+                ),
+                LogKind::Crit,
+            ),
+            BattleEvent::AbilityUsed { caster, ability } => line(
+                about(
+                    *caster,
+                    "log.ability",
+                    &[("ability", Ability::name_of(ability, locale).into())],
+                ),
+                LogKind::Info,
+            ),
+            BattleEvent::PotionDrunk { id, healed } => line(
+                about(
+                    *id,
+                    "log.potion",
+                    &[
+                        ("item", ItemKind::HealingPotion.label(locale).into()),
+                        ("amount", (*healed).into()),
+                    ],
+                ),
+                LogKind::Heal,
+            ),
+            BattleEvent::TonicDrunk { id, atk } => line(
+                about(
+                    *id,
+                    "log.tonic",
+                    &[
+                        ("item", ItemKind::AttackTonic.label(locale).into()),
+                        ("atk", (*atk).into()),
+                    ],
+                ),
+                LogKind::Info,
+            ),
+            BattleEvent::SmokeBomb { id } => line(about(*id, "log.smoke_bomb", &[]), LogKind::Info),
+            BattleEvent::Evading { id } => line(about(*id, "log.evading", &[]), LogKind::Info),
+            BattleEvent::DamageDealt {
+                source,
+                target,
+                amount,
+                crit,
+                ability,
+                effectiveness,
+            } => {
+                let message = match ability {
+                    Some(ability) => locale.format(
+                        "log.damage.ability",
+                        &[
+                            ("ability", Ability::name_of(ability, locale).into()),
+                            ("amount", (*amount).into()),
+                        ],
+                    ),
+                    None => about(*source, "log.damage", &[("amount", (*amount).into())]),
+                };
+                if *crit {
+                    line(
+                        locale.format("log.crit", &[("message", message.into())]),
+                        LogKind::Crit,
+                    );
+                } else {
+                    line(message, LogKind::Damage);
+                }
+                if let Some((message, kind)) =
+                    self.effectiveness_line(*source, *target, *effectiveness, locale)
+                {
+                    line(message, kind);
+                }
+            }
+            BattleEvent::Blocked {
+                source,
+                target,
+                amount,
+                crit,
+                effectiveness,
+                ..
+            } => {
+                let message = if self.is_you(*source) {
+                    locale.format(
+                        "log.blocked.you",
+                        &[("name", name(*target)), ("amount", (*amount).into())],
+                    )
+                } else {
+                    locale.format(
+                        "log.blocked",
+                        &[("name", name(*source)), ("amount", (*amount).into())],
+                    )
+                };
+                if *crit {
+                    line(
+                        locale.format("log.crit", &[("message", message.into())]),
+                        LogKind::Crit,
+                    );
+                } else {
+                    line(message, blocked_kind(*target));
+                }
+                if let Some((message, kind)) =
+                    self.effectiveness_line(*source, *target, *effectiveness, locale)
+                {
+                    line(message, kind);
+                }
+            }
+            BattleEvent::Missed {
+                source,
+                target,
+                evaded,
+                ..
+            } => {
+                let key = match (self.is_you(*source), self.is_you(*target), evaded) {
+                    (true, _, true) => "log.missed.you_evaded",
+                    (true, _, false) => "log.missed.you_dodged",
+                    (false, _, true) => "log.missed.evaded",
+                    (false, true, false) => "log.missed.dodged_you",
+                    (false, false, false) => "log.missed.dodged",
+                };
+                line(
+                    locale.format(key, &[("name", name(*source)), ("target", name(*target))]),
+                    LogKind::Miss,
+                );
+            }
+            BattleEvent::ShieldAbsorbed { amount, .. } => line(
+                locale.format("log.shield", &[("amount", (*amount).into())]),
+                LogKind::Heal,
+            ),
+            BattleEvent::Recoil { id, amount } => line(
+                about(*id, "battle.recoil", &[("amount", (*amount).into())]),
+                LogKind::Damage,
+            ),
+            BattleEvent::Thorns { amount, .. } => line(
+                locale.format("log.thorns", &[("amount", (*amount).into())]),
+                LogKind::Damage,
+            ),
+            BattleEvent::Healed {
+                target,
+                amount,
+                source,
+            } => {
+                let amount = ("amount", Arg::from(*amount));
+                let message = match source {
+                    HealSource::Drain => about(*target, "log.drain", &[amount]),
+                    HealSource::Ability(ability) => locale.format(
+                        "log.heal_ability",
+                        &[("ability", Ability::name_of(ability, locale).into()), amount],
+                    ),
+                    HealSource::Status => about(*target, "log.regen_status", &[amount]),
+                    HealSource::Regeneration => about(*target, "log.regeneration", &[amount]),
+                };
+                line(message, LogKind::Heal);
+            }
+            BattleEvent::Sundered { def, .. } => line(
+                locale.format("log.sundered", &[("def", (*def).into())]),
+                LogKind::Info,
+            ),
+            BattleEvent::StatusApplied {
+                target,
+                effect,
+                on_hit: true,
+            } => line(
+                about(
+                    *target,
+                    "log.afflicted",
+                    &[("status", effect.kind.label(locale).into())],
+                ),
+                LogKind::Damage,
+            ),
+            BattleEvent::StatusApplied { target, effect, .. } => line(
+                about(
+                    *target,
+                    "log.status_gained",
+                    &[
+                        ("status", effect.kind.label(locale).into()),
+                        ("count", effect.turns.into()),
+                    ],
+                ),
+                LogKind::Info,
+            ),
+            BattleEvent::StatusDamage {
+                target,
+                status,
+                amount,
+            } => line(
+                about(
+                    *target,
+                    "log.status_damage",
+                    &[
+                        ("amount", (*amount).into()),
+                        ("status", status.label(locale).into()),
+                    ],
+                ),
+                LogKind::Damage,
+            ),
+            BattleEvent::Stunned { id } => line(about(*id, "log.stunned", &[]), LogKind::Info),
+            BattleEvent::StatusExpired { id, status } => line(
+                about(
+                    *id,
+                    "log.status_expired",
+                    &[("status", status.label(locale).into())],
+                ),
+                LogKind::Info,
+            ),
+            BattleEvent::Revived { id, hp } => line(
+                about(*id, "log.revived", &[("hp", (*hp).into())]),
+                LogKind::Heal,
+            ),
+            // A lone fighter going down is what ends the battle
+            BattleEvent::KnockedOut { id } if self.side(id.side).members.len() > 1 => line(
+                locale.format("log.knocked_out", &[("name", name(*id))]),
+                LogKind::Damage,
+            ),
+            BattleEvent::KnockedOut { .. } => {}
+            BattleEvent::SteppedIn { id } => line(
+                locale.format("log.stepped_in", &[("name", name(*id))]),
+                LogKind::Info,
+            ),
+            BattleEvent::Swapped { out, incoming } => line(
+                locale.format(
+                    "log.swapped",
+                    &[("out", name(*out)), ("name", name(*incoming))],
+                ),
+                LogKind::Info,
+            ),
+            BattleEvent::PhaseStarted { id, phase } => {
+                let (message, dialogue) = self.enemy().phase_text(*phase, locale);
+                line(message, LogKind::Info);
+                if let Some(dialogue) = dialogue {
+                    line(
+                        locale.format(
+                            "log.dialogue",
+                            &[("name", name(*id)), ("line", dialogue.into())],
+                        ),
+                        LogKind::Info,
+                    );
+                }
+            }
+            BattleEvent::Summoned { id } => line(
+                locale.format("log.summoned", &[("name", name(*id))]),
+                LogKind::Info,
+            ),
+            BattleEvent::BattleEnded { result } => {
+                let loser = Arg::from(self.names(result.winner().opponent(), locale));
+                let message = if self.is_hot_seat() {
+                    locale.format(
+                        "log.hot_seat_win",
+                        &[
+                            ("winner", self.names(result.winner(), locale).into()),
+                            ("loser", loser),
+                        ],
+                    )
+                } else {
+                    match result {
+                        BattleResult::Victory => locale.format("log.victory", &[("loser", loser)]),
+                        BattleResult::Defeat if self.sides[0].members.len() > 1 => {
+                            locale.t("log.defeat_party")
+                        }
+                        BattleResult::Defeat => locale.t("log.defeat"),
+                    }
+                };
+                line(message, LogKind::Info);
+            }
+            BattleEvent::LootDropped { item } => line(
+                locale.format(
+                    "log.loot",
+                    &[
+                        ("enemy", self.enemy().name(locale).into()),
+                        ("item", item.label(locale).into()),
+                    ],
+                ),
+                LogKind::Heal,
+            ),
+        }
+    }
+
+    /// The follow-up line for a hit that was super effective or resisted.
+    fn effectiveness_line(
+        &self,
+        source: CombatantId,
+        target: CombatantId,
+        effectiveness: Effectiveness,
+        locale: Locale,
+    ) -> Option<(String, LogKind)> {
+        match effectiveness {
+            Effectiveness::SuperEffective => {
+                Some((locale.t("log.super_effective"), LogKind::Damage))
+            }
+            Effectiveness::Resisted => Some((
+                locale.format(
+                    &self.key_for(target, "log.resisted"),
+                    &[
+                        ("name", self.fighter(target).name.as_str().into()),
+                        (
+                            "affinity",
+                            self.fighter(source).affinity.label(locale).into(),
+                        ),
+                    ],
+                ),
+                LogKind::Miss,
+            )),
+            Effectiveness::Normal => None,
+        }
+    }
+}
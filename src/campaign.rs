@@ -0,0 +1,162 @@
+use crate::content;
+use crate::game_state::{BattleResult, BattleState, EnemyTemplate};
+use crate::replay::{self, BattleReplay};
+
+/// Percent of max HP recovered between encounters.
+pub const REST_HEAL_PERCENT: i32 = 40;
+
+/// Extra enemy stats (percent) per encounter cleared.
+const SCALING_PER_STAGE: u32 = 6;
+
+/// `enemy` with HP, ATK and DEF scaled to `percent`.
+fn scaled(enemy: &EnemyTemplate, percent: u32) -> EnemyTemplate {
+    EnemyTemplate {
+        hp: enemy.hp * percent / 100,
+        atk: enemy.atk * percent / 100,
+        def: enemy.def * percent / 100,
+        ..enemy.clone()
+    }
+}
+
+/// The final boss, from the game content.
+fn boss() -> EnemyTemplate {
+    content::get().bosses[0].clone()
+}
+
+/// Encounters of a run in order, each tougher than the last, ending on
+/// the boss.
+pub fn encounters() -> Vec<EnemyTemplate> {
+    let mut list: Vec<EnemyTemplate> = EnemyTemplate::all()
+        .iter()
+        .enumerate()
+        .map(|(i, enemy)| scaled(enemy, 100 + SCALING_PER_STAGE * i as u32))
+        .collect();
+    list.push(boss());
+    list
+}
+
+/// Outcome of one encounter in a run.
+#[derive(Clone, Debug)]
+pub struct StageRecord {
+    pub enemy: EnemyTemplate,
+    pub victory: bool,
+    pub score: u32,
+    pub hp_left: i32,
+    /// How the encounter was played, so its score can be checked again.
+    pub replay: BattleReplay,
+}
+
+/// A campaign in progress: which encounter is next, the HP carried into
+/// it and how the earlier ones went.
+#[derive(Clone, Debug, Default)]
+pub struct CampaignRun {
+    pub stage: usize,
+    /// Player HP carried into the next encounter (None = full health).
+    pub carried_hp: Option<i32>,
+    pub stages: Vec<StageRecord>,
+    pub over: bool,
+}
+
+impl CampaignRun {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The enemy of the current encounter.
+    pub fn encounter(&self) -> Option<EnemyTemplate> {
+        encounters().get(self.stage).cloned()
+    }
+
+    pub fn stage_count(&self) -> usize {
+        encounters().len()
+    }
+
+    /// Score banked by the finished encounters.
+    pub fn banked_score(&self) -> u32 {
+        self.stages.iter().map(|s| s.score).sum()
+    }
+
+    /// The banked score, once every finished encounter's replay has been
+    /// re-simulated as a legal, finished game that reproduces its recorded
+    /// result and score. None if any of them doesn't.
+    pub fn verified_score(&self) -> Option<u32> {
+        self.stages
+            .iter()
+            .all(|s| {
+                let outcome = replay::replay(&s.replay);
+                outcome.complete
+                    && outcome.score == s.score
+                    && (outcome.result == Some(BattleResult::Victory)) == s.victory
+            })
+            .then(|| self.banked_score())
+    }
+
+    /// Whether every encounter was won.
+    pub fn cleared(&self) -> bool {
+        self.over && self.stages.last().is_some_and(|s| s.victory)
+    }
+
+    /// Record a finished battle and its replay, and move on: a victory
+    /// advances to the next encounter after a short rest, a defeat (or
+    /// beating the boss) ends the run.
+    pub fn record(&mut self, battle: &BattleState, replay: BattleReplay) {
+        let victory = battle.result == Some(BattleResult::Victory);
+        self.stages.push(StageRecord {
+            enemy: battle.enemy().clone(),
+            victory,
+            score: battle.score,
+            hp_left: battle.player().hp.max(0),
+            replay,
+        });
+        self.stage += 1;
+        if !victory || self.stage >= self.stage_count() {
+            self.over = true;
+            self.carried_hp = None;
+        } else {
+            let player = battle.player();
+            let rest = player.max_hp * REST_HEAL_PERCENT / 100;
+            self.carried_hp = Some((player.hp + rest).min(player.max_hp));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::{Action, CharacterTemplate};
+
+    /// A run of the first god through the first `stages` encounters,
+    /// attacking every turn.
+    fn run_through(stages: usize) -> CampaignRun {
+        let party = [CharacterTemplate::all().remove(0)];
+        let mut run = CampaignRun::new();
+        for seed in 0..stages as u64 {
+            let Some(enemy) = run.encounter().filter(|_| !run.over) else {
+                break;
+            };
+            let mut battle = BattleState::team_battle(&party, &[enemy], seed);
+            if let Some(hp) = run.carried_hp {
+                battle = battle.with_player_hp(hp);
+            }
+            replay::enemy_phase(&mut battle);
+            while replay::step(&mut battle, Action::Attack) {}
+            let rec = BattleReplay::from_battle(&party, None, &battle);
+            run.record(&battle, rec);
+        }
+        run
+    }
+
+    #[test]
+    fn honest_run_verifies() {
+        let run = run_through(3);
+        assert!(!run.stages.is_empty());
+        assert_eq!(run.verified_score(), Some(run.banked_score()));
+    }
+
+    #[test]
+    fn inflated_stage_score_fails_verification() {
+        let mut run = run_through(3);
+        run.stages[0].score += 50;
+        assert_eq!(run.verified_score(), None);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::game_state::Action;
+use crate::locale::Locale;
+
+/// Attacks in a row before momentum starts to build.
+pub const MOMENTUM_AFTER: usize = 2;
+
+/// Most momentum a streak of attacks can build.
+pub const MAX_MOMENTUM: u32 = 3;
+
+/// Extra damage (percent) per level of momentum.
+pub const MOMENTUM_PERCENT: u32 = 10;
+
+/// Extra damage (percent) of an attack straight after defending.
+pub const COUNTER_PERCENT: u32 = 50;
+
+/// Moves a fighter remembers; enough to see a full momentum streak.
+const MEMORY: usize = MOMENTUM_AFTER + MAX_MOMENTUM as usize;
+
+/// A pattern of moves that powers up the attack finishing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComboKind {
+    /// The third attack in a row and on; the level grows with the streak.
+    Momentum(u32),
+    /// An attack right after a Defend.
+    Counter,
+}
+
+impl ComboKind {
+    /// Extra damage the finishing attack deals, in percent.
+    pub fn bonus_percent(self) -> u32 {
+        match self {
+            ComboKind::Momentum(level) => level * MOMENTUM_PERCENT,
+            ComboKind::Counter => COUNTER_PERCENT,
+        }
+    }
+
+    pub fn label(self, locale: Locale) -> String {
+        match self {
+            ComboKind::Momentum(level) => {
+                locale.format("combo.momentum", &[("level", level.into())])
+            }
+            ComboKind::Counter => locale.t("combo.counter"),
+        }
+    }
+}
+
+/// A fighter's most recent moves, oldest first. Only people's moves are
+/// tracked; the AI doesn't play combos.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Combo {
+    recent: Vec<Action>,
+}
+
+impl Combo {
+    /// Remember a move that took the turn. Aiming is free and doesn't count.
+    pub fn record(&mut self, action: Action) {
+        if matches!(action, Action::Target(_)) {
+            return;
+        }
+        self.recent.push(action);
+        if self.recent.len() > MEMORY {
+            self.recent.remove(0);
+        }
+    }
+
+    /// The combo an Attack made now would finish, if any.
+    pub fn next_attack(&self) -> Option<ComboKind> {
+        if self.recent.last() == Some(&Action::Defend) {
+            return Some(ComboKind::Counter);
+        }
+        let streak = self
+            .recent
+            .iter()
+            .rev()
+            .take_while(|a| **a == Action::Attack)
+            .count();
+        let level = (streak + 1).saturating_sub(MOMENTUM_AFTER) as u32;
+        (level > 0).then_some(ComboKind::Momentum(level.min(MAX_MOMENTUM)))
+    }
+}
@@ -1,13 +1,20 @@
-use leptos::prelude::*;
 use leptos::ev::MouseEvent;
+use leptos::prelude::*;
 
+use crate::equipment::Loadout;
 use crate::game_state::CharacterTemplate;
+use crate::locale::Locale;
 
 #[component]
 pub fn CharacterCard(
     character: CharacterTemplate,
-    index: usize,
     #[prop(into)] selected: Signal<bool>,
+    /// Level reached by the minted character; stats are shown at this level.
+    #[prop(into, optional)]
+    level: Option<Signal<u32>>,
+    /// Equipment the minted character wears; counted in the stats shown.
+    #[prop(into, optional)]
+    loadout: Option<Signal<Loadout>>,
     on_click: impl Fn(MouseEvent) + 'static,
 ) -> impl IntoView {
     let card_class = move || {
@@ -18,9 +25,10 @@ pub fn CharacterCard(
         }
     };
 
-    // Simple SVG character silhouette that varies by index
-    let hue = index * 60;
-    let svg_color = format!("hsl({}, 80%, 60%)", hue);
+    let locale = expect_context::<RwSignal<Locale>>();
+
+    // Simple SVG character silhouette in the character's colour
+    let svg_color = format!("hsl({}, 80%, 60%)", character.hue);
 
     view! {
         <div class={card_class} on:click=on_click>
@@ -38,13 +46,51 @@ pub fn CharacterCard(
                     <line x1="30" y1="42" x2="40" y2="55" stroke={svg_color} stroke-width="2"/>
                 </svg>
             </div>
-            <div class="character-name">{character.name.clone()}</div>
+            <div class="character-name">
+                {character.name.clone()}
+                {move || level.map(|l| l.get()).filter(|&l| l > 1).map(|l| {
+                    format!(" {}", locale.get().format("level", &[("level", l.into())]))
+                })}
+            </div>
             <div class="character-stats">
-                {format!("HP:{} ATK:{} DEF:{}", character.hp, character.atk, character.def)}
+                {
+                    let character = character.clone();
+                    move || {
+                        let c = character
+                            .at_level(level.map_or(1, |l| l.get()))
+                            .equip(&loadout.map(|l| l.get()).unwrap_or_default());
+                        locale.get().format("stats.full", &[
+                            ("hp", c.hp.into()),
+                            ("atk", c.atk.into()),
+                            ("def", c.def.into()),
+                            ("spd", c.speed.into()),
+                        ])
+                    }
+                }
             </div>
             <div style="font-size: 0.65rem; color: var(--text-dim); margin-top: 0.3rem;">
-                {character.description.clone()}
+                {
+                    let character = character.clone();
+                    move || character.description(locale.get())
+                }
+            </div>
+            <div style="font-size: 0.65rem; color: var(--green-dim); margin-top: 0.3rem;">
+                {move || {
+                    let l = locale.get();
+                    l.format("card.affinity", &[("affinity", character.affinity.label(l).into())])
+                }}
             </div>
+            {character.ability_list().into_iter().map(|a| {
+                let title = a.clone();
+                view! {
+                    <div style="font-size: 0.65rem; color: var(--yellow); margin-top: 0.3rem;" title=move || title.description(locale.get())>
+                        {move || {
+                            let l = locale.get();
+                            l.format("card.ability", &[("ability", a.name(l).into())])
+                        }}
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
         </div>
     }
 }
@@ -0,0 +1,81 @@
+use leptos::ev::MouseEvent;
+use leptos::prelude::*;
+
+use crate::game_state::EnemyTemplate;
+use crate::locale::Locale;
+
+#[component]
+pub fn EnemyCard(
+    enemy: EnemyTemplate,
+    #[prop(into)] selected: Signal<bool>,
+    on_click: impl Fn(MouseEvent) + 'static,
+) -> impl IntoView {
+    let card_class = move || {
+        if selected.get() {
+            "character-card enemy-card selected"
+        } else {
+            "character-card enemy-card"
+        }
+    };
+
+    let locale = expect_context::<RwSignal<Locale>>();
+    let svg_color = format!("hsl({}, 80%, 60%)", enemy.hue);
+    let (affinity, behaviour, on_hit) = (enemy.affinity, enemy.behaviour, enemy.on_hit);
+    let traits = move || {
+        let l = locale.get();
+        let mut args = vec![
+            ("affinity", affinity.label(l).into()),
+            ("behaviour", behaviour.label(l).into()),
+        ];
+        match on_hit {
+            Some(effect) => {
+                args.push(("status", effect.kind.label(l).into()));
+                l.format("card.enemy_traits_on_hit", &args)
+            }
+            None => l.format("card.enemy_traits", &args),
+        }
+    };
+
+    view! {
+        <div class={card_class} on:click=on_click>
+            <div class="character-avatar">
+                <svg width="60" height="60" viewBox="0 0 60 60">
+                    // Head
+                    <circle cx="30" cy="15" r="10" fill="none" stroke={svg_color.clone()} stroke-width="2"/>
+                    // Body
+                    <line x1="30" y1="25" x2="30" y2="42" stroke={svg_color.clone()} stroke-width="2"/>
+                    // Arms
+                    <line x1="30" y1="30" x2="18" y2="38" stroke={svg_color.clone()} stroke-width="2"/>
+                    <line x1="30" y1="30" x2="42" y2="38" stroke={svg_color.clone()} stroke-width="2"/>
+                    // Legs
+                    <line x1="30" y1="42" x2="20" y2="55" stroke={svg_color.clone()} stroke-width="2"/>
+                    <line x1="30" y1="42" x2="40" y2="55" stroke={svg_color} stroke-width="2"/>
+                </svg>
+            </div>
+            <div class="character-name">{
+                let enemy = enemy.clone();
+                move || enemy.name(locale.get())
+            }</div>
+            <div class="character-stats">
+                {
+                    let (hp, atk, def, spd) = (enemy.hp, enemy.atk, enemy.def, enemy.speed);
+                    move || locale.get().format("stats.full", &[
+                        ("hp", hp.into()),
+                        ("atk", atk.into()),
+                        ("def", def.into()),
+                        ("spd", spd.into()),
+                    ])
+                }
+            </div>
+            <div style="font-size: 0.65rem; color: var(--text-dim); margin-top: 0.3rem;">
+                {
+                    let enemy = enemy.clone();
+                    move || enemy.description(locale.get())
+                }
+            </div>
+            <div style="font-size: 0.65rem; color: var(--red); margin-top: 0.3rem;">
+                {traits}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,28 @@
+use leptos::prelude::*;
+
+use crate::locale::Locale;
+
+/// Picks the language the UI is shown in.
+#[component]
+pub fn LanguageSelect() -> impl IntoView {
+    let locale = expect_context::<RwSignal<Locale>>();
+
+    let on_change = move |ev| {
+        if let Some(l) = Locale::from_code(&event_target_value(&ev)) {
+            locale.set(l);
+        }
+    };
+
+    view! {
+        <select
+            class="language-select"
+            title=move || locale.get().t("language.label")
+            aria-label=move || locale.get().t("language.label")
+            on:change=on_change
+        >
+            {Locale::all().into_iter().map(|l| view! {
+                <option value={l.code()} selected=move || locale.get() == l>{l.native_name()}</option>
+            }).collect::<Vec<_>>()}
+        </select>
+    }
+}
@@ -1,4 +1,7 @@
 pub mod character_card;
+pub mod enemy_card;
 pub mod health_bar;
+pub mod language_select;
+pub mod status_badges;
 pub mod stick_figure;
 pub mod wallet_button;
@@ -0,0 +1,28 @@
+use leptos::prelude::*;
+
+use crate::locale::Locale;
+use crate::status::{StatusEffect, StatusKind};
+
+#[component]
+pub fn StatusBadges(#[prop(into)] effects: Signal<Vec<StatusEffect>>) -> impl IntoView {
+    let locale = expect_context::<RwSignal<Locale>>();
+    view! {
+        <div class="status-badges">
+            {move || {
+                let l = locale.get();
+                effects.get().into_iter().map(|e| {
+                    let class = match e.kind {
+                        StatusKind::Poison | StatusKind::Burn => "status-badge harmful",
+                        StatusKind::Stun => "status-badge stun",
+                        StatusKind::Shield | StatusKind::Regen => "status-badge helpful",
+                    };
+                    let text = match e.kind {
+                        StatusKind::Shield => format!("{} {} ({})", e.kind.label(l), e.potency, e.turns),
+                        _ => format!("{} ({})", e.kind.label(l), e.turns),
+                    };
+                    view! { <span class={class}>{text}</span> }
+                }).collect::<Vec<_>>()
+            }}
+        </div>
+    }
+}
@@ -1,11 +1,19 @@
 use leptos::prelude::*;
 
+use crate::equipment::WeaponStyle;
+use crate::game_state::LogKind;
+
 #[component]
 pub fn StickFigure(
     #[prop(default = false)] is_enemy: bool,
+    /// Override the figure colour with an HSL hue (defaults to the theme colour).
+    #[prop(into, optional)] hue: Option<Signal<u32>>,
+    /// Weapon drawn in the right hand (a sword unless equipped otherwise).
+    #[prop(optional)] weapon: WeaponStyle,
     #[prop(into, optional)] animating: Option<Signal<bool>>,
     #[prop(into, optional)] defending: Option<Signal<bool>>,
-    #[prop(into, optional)] hit: Option<Signal<bool>>,
+    /// How the last strike on this figure landed; crits and misses animate differently.
+    #[prop(into, optional)] hit: Option<Signal<Option<LogKind>>>,
 ) -> impl IntoView {
     let base_class = if is_enemy {
         "stick-figure enemy"
@@ -26,8 +34,11 @@ pub fn StickFigure(
             }
         }
         if let Some(h) = hit {
-            if h.get() {
-                cls.push_str(" hit");
+            match h.get() {
+                Some(LogKind::Crit) => cls.push_str(" hit crit"),
+                Some(LogKind::Miss) => cls.push_str(" dodge"),
+                Some(_) => cls.push_str(" hit"),
+                None => {}
             }
         }
         cls
@@ -42,8 +53,13 @@ pub fn StickFigure(
 
     let is_defending = move || defending.map(|d| d.get()).unwrap_or(false);
 
+    let color_style = move || {
+        hue.map(|h| format!("--figure-color: hsl({}, 80%, 60%)", h.get()))
+            .unwrap_or_default()
+    };
+
     view! {
-        <svg class={class} viewBox="0 0 80 120">
+        <svg class={class} style={color_style} viewBox="0 0 80 120">
             <g transform={transform} class="body">
                 // Head
                 <circle cx="40" cy="20" r="12"/>
@@ -67,15 +83,29 @@ pub fn StickFigure(
                 }}
                 // Right arm (weapon arm)
                 <line x1="40" y1="42" x2="60" y2="50"/>
-                // Sword (shown when NOT defending)
+                // Weapon (shown when NOT defending)
                 {move || {
-                    if !is_defending() {
-                        Some(view! {
-                            <line x1="60" y1="50" x2="72" y2="38" stroke-width="3" class="weapon"/>
-                        })
-                    } else {
-                        None
+                    if is_defending() {
+                        return None;
                     }
+                    Some(match weapon {
+                        WeaponStyle::Sword => view! {
+                            <line x1="60" y1="50" x2="72" y2="38" stroke-width="3" class="weapon"/>
+                        }.into_any(),
+                        WeaponStyle::Axe => view! {
+                            <g class="weapon">
+                                <line x1="60" y1="50" x2="70" y2="34" stroke-width="2"/>
+                                <path d="M67 36 L76 33 L74 44 Z" stroke-width="2"/>
+                            </g>
+                        }.into_any(),
+                        WeaponStyle::Hammer => view! {
+                            <g class="weapon">
+                                <line x1="60" y1="50" x2="68" y2="34" stroke-width="2"/>
+                                <rect x="61" y="27" width="14" height="8" stroke-width="2"
+                                    transform="rotate(28 68 31)"/>
+                            </g>
+                        }.into_any(),
+                    })
                 }}
                 // Legs
                 <line x1="40" y1="70" x2="25" y2="100"/>
@@ -2,11 +2,13 @@ use leptos::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
 use crate::app::WalletState;
+use crate::locale::Locale;
 use crate::wallet;
 
 #[component]
 pub fn WalletButton() -> impl IntoView {
     let wallet_state = expect_context::<RwSignal<WalletState>>();
+    let locale = expect_context::<RwSignal<Locale>>();
     let loading = RwSignal::new(false);
 
     let on_click = move |_| {
@@ -49,9 +51,9 @@ pub fn WalletButton() -> impl IntoView {
                 let truncated = format!("{}...{}", &pk[..4], &pk[pk.len() - 4..]);
                 return truncated;
             }
-            "Connected".to_string()
+            locale.get().t("wallet.connected")
         } else {
-            "Connect".to_string()
+            locale.get().t("wallet.connect")
         }
     };
 
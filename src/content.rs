@@ -0,0 +1,276 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::abilities::Ability;
+use crate::equipment::{Equipment, Slot};
+use crate::game_state::{CharacterTemplate, EnemyTemplate};
+use crate::items::{ItemDef, ItemKind};
+use crate::locale::Locale;
+use crate::progression;
+
+/// Game content, compiled in. Designers add characters, enemies and the
+/// rest here without touching Rust.
+const CONTENT_JSON: &str = include_str!("../data/content.json");
+
+/// Everything the game is built from. Read through the `all()` and
+/// `by_id()` constructors (`CharacterTemplate::all()`, `Ability::by_id()`,
+/// ...).
+#[derive(Debug, Deserialize)]
+pub struct Content {
+    pub characters: Vec<CharacterTemplate>,
+    pub enemies: Vec<EnemyTemplate>,
+    /// Campaign bosses; a run ends on the first one.
+    pub bosses: Vec<EnemyTemplate>,
+    /// Enemies that only appear when a boss phase summons them.
+    #[serde(default)]
+    pub minions: Vec<EnemyTemplate>,
+    pub abilities: Vec<Ability>,
+    pub items: Vec<ItemDef>,
+    pub equipment: Vec<Equipment>,
+}
+
+static CONTENT: OnceLock<Content> = OnceLock::new();
+
+/// The game's content, parsed and validated on first use. Bad content is
+/// a mistake in the build, so this panics listing every problem.
+pub fn get() -> &'static Content {
+    CONTENT.get_or_init(|| {
+        parse(CONTENT_JSON).unwrap_or_else(|e| panic!("Invalid data/content.json:\n{}", e))
+    })
+}
+
+/// Parse and validate content. The error lists every bad entry, one per
+/// line.
+pub fn parse(json: &str) -> Result<Content, String> {
+    let content: Content = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let errors = content.validate();
+    if errors.is_empty() {
+        Ok(content)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// Catalogue key of a piece of content text, e.g.
+/// `fighter.dark_knight.description`: the name lowercased, with anything
+/// but letters and digits turned into `_`.
+pub fn text_key(kind: &str, name: &str, field: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}.{}.{}", kind, slug, field)
+}
+
+/// The catalogue keys in `keys` that no catalogue has.
+fn missing_text(keys: impl IntoIterator<Item = String>) -> Vec<String> {
+    keys.into_iter()
+        .filter(|key| !Locale::has(key))
+        .map(|key| format!("no \"{}\" in data/locales", key))
+        .collect()
+}
+
+/// Entries of `names` that appear more than once.
+fn duplicates<'a>(names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    names.filter(|n| !seen.insert(*n)).collect()
+}
+
+impl Content {
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        for (list, empty) in [
+            ("characters", self.characters.is_empty()),
+            ("enemies", self.enemies.is_empty()),
+            ("bosses", self.bosses.is_empty()),
+        ] {
+            if empty {
+                errors.push(format!("{}: needs at least one entry", list));
+            }
+        }
+
+        let abilities: HashSet<&str> = self.abilities.iter().map(|a| a.id.as_str()).collect();
+        let equipment: HashSet<&str> = self.equipment.iter().map(|e| e.id.as_str()).collect();
+        let unknown_abilities = |ids: &[String]| -> Vec<String> {
+            ids.iter()
+                .filter(|id| !abilities.contains(id.as_str()))
+                .map(|id| format!("unknown ability \"{}\"", id))
+                .collect()
+        };
+
+        for (i, c) in self.characters.iter().enumerate() {
+            let mut problems = unknown_abilities(&c.abilities);
+            problems.extend(
+                c.equipment
+                    .iter()
+                    .filter(|id| !equipment.contains(id.as_str()))
+                    .map(|id| format!("unknown equipment \"{}\"", id)),
+            );
+            problems.extend(fighter_problems(c.hp, c.speed, c.hue));
+            problems.extend(missing_text(c.description.is_empty().then(|| {
+                text_key("fighter", &c.name, "description")
+            })));
+            for p in problems {
+                errors.push(format!("characters[{}] \"{}\": {}", i, c.name, p));
+            }
+        }
+
+        for (list, enemies) in [
+            ("enemies", &self.enemies),
+            ("bosses", &self.bosses),
+            ("minions", &self.minions),
+        ] {
+            for (i, e) in enemies.iter().enumerate() {
+                let mut problems = unknown_abilities(&e.abilities);
+                problems.extend(fighter_problems(e.hp, e.speed, e.hue));
+                problems.extend(missing_text(
+                    std::iter::once(text_key("enemy", &e.name, "name")).chain(
+                        e.description
+                            .is_empty()
+                            .then(|| text_key("fighter", &e.name, "description")),
+                    ),
+                ));
+                let mut last = 100;
+                for (p, phase) in e.phases.iter().enumerate() {
+                    if phase.below_percent == 0 || phase.below_percent >= last {
+                        problems.push(format!(
+                            "phase at {}% must be below 100% and the phase before it",
+                            phase.below_percent
+                        ));
+                    }
+                    last = phase.below_percent;
+                    problems.extend(
+                        phase
+                            .summon
+                            .iter()
+                            .filter(|n| !self.minions.iter().any(|m| m.name == **n))
+                            .map(|n| format!("phase summons unknown minion \"{}\"", n)),
+                    );
+                    let (message, dialogue) = EnemyTemplate::phase_keys(&e.name, p);
+                    problems.extend(missing_text(
+                        std::iter::once(message).chain(phase.dialogue.then_some(dialogue)),
+                    ));
+                }
+                for p in problems {
+                    errors.push(format!("{}[{}] \"{}\": {}", list, i, e.name, p));
+                }
+            }
+        }
+
+        for (i, a) in self.abilities.iter().enumerate() {
+            if a.cooldown == 0 {
+                errors.push(format!(
+                    "abilities[{}] \"{}\": cooldown must be at least 1",
+                    i, a.id
+                ));
+            }
+            if a.effects.is_empty() {
+                errors.push(format!("abilities[{}] \"{}\": has no effects", i, a.id));
+            }
+            for p in missing_text([
+                text_key("ability", &a.id, "name"),
+                text_key("ability", &a.id, "description"),
+            ]) {
+                errors.push(format!("abilities[{}] \"{}\": {}", i, a.id, p));
+            }
+        }
+
+        for kind in ItemKind::all() {
+            let count = self.items.iter().filter(|d| d.kind == kind).count();
+            if count != 1 {
+                errors.push(format!(
+                    "items: {:?} is defined {} times, expected once",
+                    kind, count
+                ));
+            }
+        }
+        let weights: u32 = self.items.iter().map(|d| d.drop_weight).sum();
+        if weights != 100 {
+            errors.push(format!(
+                "items: drop weights add up to {}, expected 100",
+                weights
+            ));
+        }
+
+        for (i, e) in self.equipment.iter().enumerate() {
+            if !(1..=progression::MAX_LEVEL).contains(&e.unlock_level) {
+                errors.push(format!(
+                    "equipment[{}] \"{}\": unlock_level must be 1 to {}",
+                    i,
+                    e.id,
+                    progression::MAX_LEVEL
+                ));
+            }
+            if (e.slot == Slot::Weapon) != e.style.is_some() {
+                errors.push(format!(
+                    "equipment[{}] \"{}\": weapons need a style and nothing else may have one",
+                    i, e.id
+                ));
+            }
+            for p in missing_text([text_key("equipment", &e.id, "name")]) {
+                errors.push(format!("equipment[{}] \"{}\": {}", i, e.id, p));
+            }
+        }
+
+        // Characters and enemies look their descriptions up by name
+        for c in &self.characters {
+            let mut enemies = self.enemies.iter().chain(&self.bosses).chain(&self.minions);
+            if enemies.any(|e| e.name == c.name) {
+                errors.push(format!(
+                    "characters: \"{}\" is also an enemy, boss or minion",
+                    c.name
+                ));
+            }
+        }
+
+        let names = self
+            .enemies
+            .iter()
+            .chain(&self.bosses)
+            .chain(&self.minions)
+            .map(|e| e.name.as_str());
+        for (list, dupes) in [
+            (
+                "characters",
+                duplicates(self.characters.iter().map(|c| c.name.as_str())),
+            ),
+            ("enemies, bosses and minions", duplicates(names)),
+            (
+                "abilities",
+                duplicates(self.abilities.iter().map(|a| a.id.as_str())),
+            ),
+            (
+                "equipment",
+                duplicates(self.equipment.iter().map(|e| e.id.as_str())),
+            ),
+        ] {
+            for name in dupes {
+                errors.push(format!("{}: \"{}\" is defined more than once", list, name));
+            }
+        }
+        errors
+    }
+}
+
+/// Problems with the stats every fighter has.
+fn fighter_problems(hp: u32, speed: u32, hue: u32) -> Vec<String> {
+    let mut problems = Vec::new();
+    if hp == 0 {
+        problems.push("hp must be above 0".to_string());
+    }
+    if speed == 0 {
+        problems.push("speed must be above 0".to_string());
+    }
+    if hue >= 360 {
+        problems.push(format!("hue {} is not below 360", hue));
+    }
+    problems
+}
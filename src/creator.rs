@@ -0,0 +1,245 @@
+use serde::{Deserialize, Serialize};
+
+use crate::affinity::Affinity;
+use crate::game_state::CharacterTemplate;
+use crate::initiative;
+use crate::locale::Locale;
+
+/// Points a custom hero can spread across HP, ATK and DEF.
+pub const POINT_BUDGET: u32 = 28;
+/// Most points any one stat can take.
+pub const MAX_POINTS_PER_STAT: u32 = 16;
+
+/// Stats of a hero with no points spent, and what each point adds.
+pub const BASE_HP: u32 = 60;
+pub const HP_PER_POINT: u32 = 5;
+pub const BASE_ATK: u32 = 8;
+pub const BASE_DEF: u32 = 4;
+
+pub const MAX_NAME_LEN: usize = 16;
+pub const MAX_DESCRIPTION_LEN: usize = 60;
+
+/// A hero being built in the creator: text, colour and the point spread.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeroDraft {
+    pub name: String,
+    pub description: String,
+    pub hue: u32,
+    pub hp_points: u32,
+    pub atk_points: u32,
+    pub def_points: u32,
+}
+
+impl HeroDraft {
+    pub fn points_spent(&self) -> u32 {
+        self.hp_points
+            .saturating_add(self.atk_points)
+            .saturating_add(self.def_points)
+    }
+
+    pub fn points_left(&self) -> u32 {
+        POINT_BUDGET.saturating_sub(self.points_spent())
+    }
+
+    /// Every rule the draft breaks, empty if it can be built.
+    pub fn problems(&self, locale: Locale) -> Vec<String> {
+        let mut problems = Vec::new();
+        let name = self.name.trim();
+        if name.is_empty() {
+            problems.push(locale.t("creator.error.no_name"));
+        } else if name.chars().count() > MAX_NAME_LEN {
+            problems.push(locale.format(
+                "creator.error.name_too_long",
+                &[("max", MAX_NAME_LEN.into())],
+            ));
+        } else if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '\'')
+        {
+            problems.push(locale.t("creator.error.name_characters"));
+        }
+        if self.description.chars().any(char::is_control) {
+            problems.push(locale.t("creator.error.description_control"));
+        } else if self.description.trim().chars().count() > MAX_DESCRIPTION_LEN {
+            problems.push(locale.format(
+                "creator.error.description_too_long",
+                &[("max", MAX_DESCRIPTION_LEN.into())],
+            ));
+        }
+        if self.hue >= 360 {
+            problems.push(locale.format("creator.error.hue", &[("hue", self.hue.into())]));
+        }
+        for (stat, points) in [
+            ("stat.hp", self.hp_points),
+            ("stat.atk", self.atk_points),
+            ("stat.def", self.def_points),
+        ] {
+            if points > MAX_POINTS_PER_STAT {
+                problems.push(locale.format(
+                    "creator.error.stat_points",
+                    &[
+                        ("stat", locale.t(stat).into()),
+                        ("points", points.into()),
+                        ("max", MAX_POINTS_PER_STAT.into()),
+                    ],
+                ));
+            }
+        }
+        if self.points_spent() > POINT_BUDGET {
+            problems.push(locale.format(
+                "creator.error.budget",
+                &[
+                    ("spent", self.points_spent().into()),
+                    ("budget", POINT_BUDGET.into()),
+                ],
+            ));
+        }
+        problems
+    }
+
+    /// The hero as a character template, or every rule the draft breaks.
+    pub fn build(&self, locale: Locale) -> Result<CharacterTemplate, String> {
+        let problems = self.problems(locale);
+        if !problems.is_empty() {
+            return Err(problems.join(". "));
+        }
+        Ok(CharacterTemplate {
+            name: self.name.trim().to_string(),
+            hp: BASE_HP + self.hp_points * HP_PER_POINT,
+            atk: BASE_ATK + self.atk_points,
+            def: BASE_DEF + self.def_points,
+            speed: initiative::DEFAULT_SPEED,
+            hue: self.hue,
+            affinity: Affinity::Neutral,
+            description: self.description.trim().to_string(),
+            abilities: Vec::new(),
+            equipment: Vec::new(),
+        })
+    }
+
+    /// The draft a custom hero was built from, if its stats are a legal
+    /// spread.
+    pub fn from_template(template: &CharacterTemplate, locale: Locale) -> Result<Self, String> {
+        if template.hp < BASE_HP || template.atk < BASE_ATK || template.def < BASE_DEF {
+            return Err(locale.format(
+                "creator.error.below_base",
+                &[("name", template.name.as_str().into())],
+            ));
+        }
+        if !(template.hp - BASE_HP).is_multiple_of(HP_PER_POINT) {
+            return Err(locale.format(
+                "creator.error.hp_unreachable",
+                &[("hp", template.hp.into())],
+            ));
+        }
+        let draft = Self {
+            name: template.name.clone(),
+            description: template.description.clone(),
+            hue: template.hue,
+            hp_points: (template.hp - BASE_HP) / HP_PER_POINT,
+            atk_points: template.atk - BASE_ATK,
+            def_points: template.def - BASE_DEF,
+        };
+        draft.build(locale)?;
+        Ok(draft)
+    }
+}
+
+/// Check a custom hero before it is minted or fought with: it must be
+/// exactly what the creator builds from some legal draft.
+pub fn validate(template: &CharacterTemplate, locale: Locale) -> Result<(), String> {
+    let draft = HeroDraft::from_template(template, locale)?;
+    let rebuilt = draft.build(locale)?;
+    if rebuilt.name != template.name
+        || rebuilt.description != template.description
+        || rebuilt.speed != template.speed
+        || rebuilt.affinity != template.affinity
+        || !template.abilities.is_empty()
+        || !template.equipment.is_empty()
+    {
+        return Err(locale.format(
+            "creator.error.not_creatable",
+            &[("name", template.name.as_str().into())],
+        ));
+    }
+    Ok(())
+}
+
+/// Whether a custom hero passes `validate`.
+pub fn is_valid(template: &CharacterTemplate) -> bool {
+    validate(template, Locale::En).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft(hp_points: u32, atk_points: u32, def_points: u32) -> HeroDraft {
+        HeroDraft {
+            name: "Sigrun".to_string(),
+            description: "A test hero".to_string(),
+            hue: 200,
+            hp_points,
+            atk_points,
+            def_points,
+        }
+    }
+
+    fn budget_error(draft: &HeroDraft) -> String {
+        Locale::En.format(
+            "creator.error.budget",
+            &[
+                ("spent", draft.points_spent().into()),
+                ("budget", POINT_BUDGET.into()),
+            ],
+        )
+    }
+
+    fn stat_error(stat: &str, points: u32) -> String {
+        Locale::En.format(
+            "creator.error.stat_points",
+            &[
+                ("stat", Locale::En.t(stat).into()),
+                ("points", points.into()),
+                ("max", MAX_POINTS_PER_STAT.into()),
+            ],
+        )
+    }
+
+    #[test]
+    fn spending_exactly_the_budget_is_allowed() {
+        let d = draft(12, 10, 6);
+        assert_eq!(d.points_spent(), POINT_BUDGET);
+        assert_eq!(d.points_left(), 0);
+        assert!(d.problems(Locale::En).is_empty());
+        assert!(d.build(Locale::En).is_ok());
+    }
+
+    #[test]
+    fn one_point_over_the_budget_is_rejected() {
+        let d = draft(12, 10, 7);
+        assert_eq!(d.points_spent(), POINT_BUDGET + 1);
+        assert_eq!(d.problems(Locale::En), vec![budget_error(&d)]);
+        assert!(d.build(Locale::En).is_err());
+    }
+
+    #[test]
+    fn each_stat_can_reach_its_cap_but_not_pass_it() {
+        let stats = ["stat.hp", "stat.atk", "stat.def"];
+        for (i, stat) in stats.into_iter().enumerate() {
+            let mut points = [0; 3];
+            points[i] = MAX_POINTS_PER_STAT;
+            let at_cap = draft(points[0], points[1], points[2]);
+            assert!(at_cap.problems(Locale::En).is_empty(), "{} at cap", stat);
+
+            points[i] = MAX_POINTS_PER_STAT + 1;
+            let over = draft(points[0], points[1], points[2]);
+            assert_eq!(
+                over.problems(Locale::En),
+                vec![stat_error(stat, MAX_POINTS_PER_STAT + 1)],
+                "{} over cap",
+                stat
+            );
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::abilities::{Ability, AbilityEffect, AbilityTarget};
+use crate::game_state::{Action, BattleState, Combatant, EnemyBehaviour, Team, Turn};
+
+/// Picks the action for `side`'s acting fighter at the start of its turn; it must be one
+/// `BattleState::act` accepts (e.g. only abilities that are ready).
+/// Implementations must be pure functions of the battle state so that
+/// replays re-simulate the exact same fight.
+pub trait EnemyAi {
+    fn choose(&self, battle: &BattleState, side: Turn) -> Action;
+}
+
+/// The AI a behaviour maps to.
+pub fn for_behaviour(behaviour: EnemyBehaviour) -> Box<dyn EnemyAi> {
+    match behaviour {
+        EnemyBehaviour::Aggressive => Box::new(AggressiveAi),
+        EnemyBehaviour::Cautious => Box::new(CautiousAi),
+        EnemyBehaviour::Cunning => Box::new(PatternReaderAi),
+        EnemyBehaviour::Tactician => Box::new(LookaheadAi { depth: 2 }),
+    }
+}
+
+fn damage_percent(ability: &Ability) -> u32 {
+    ability
+        .effects
+        .iter()
+        .map(|e| match e {
+            AbilityEffect::Damage { percent, .. } => *percent,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn is_offensive(ability: &Ability) -> bool {
+    ability.target == AbilityTarget::Enemy && damage_percent(ability) > 0
+}
+
+fn is_defensive(ability: &Ability) -> bool {
+    ability.target == AbilityTarget::Caster
+}
+
+fn pierces(ability: &Ability) -> bool {
+    ability
+        .effects
+        .iter()
+        .any(|e| matches!(e, AbilityEffect::Damage { pierce: true, .. }))
+}
+
+/// Ready ability of `fighter` matching `pred` with the biggest damage multiplier.
+fn best_ready(fighter: &Combatant, pred: impl Fn(&Ability) -> bool) -> Option<Action> {
+    fighter
+        .ready_abilities()
+        .filter(|(_, a)| pred(a))
+        .max_by_key(|(i, a)| (damage_percent(a), usize::MAX - i))
+        .map(|(i, _)| Action::Ability(i as u8))
+}
+
+fn hp_percent(fighter: &Combatant) -> i32 {
+    if fighter.max_hp <= 0 {
+        0
+    } else {
+        fighter.hp * 100 / fighter.max_hp
+    }
+}
+
+/// HP left across a whole party, as a percentage of its max HP.
+fn team_hp_percent(team: &Team) -> i32 {
+    let hp: i32 = team.members.iter().map(|c| c.hp).sum();
+    let max: i32 = team.members.iter().map(|c| c.max_hp).sum();
+    if max <= 0 {
+        0
+    } else {
+        hp * 100 / max
+    }
+}
+
+/// Turn-taking actions `side` has made so far, most recent first.
+fn history(battle: &BattleState, side: Turn) -> impl Iterator<Item = Action> + '_ {
+    battle
+        .actions
+        .iter()
+        .rev()
+        .filter(move |(s, a)| *s == side && !matches!(a, Action::Target(_)))
+        .map(|(_, a)| *a)
+}
+
+/// Always hits as hard as it can and never defends.
+pub struct AggressiveAi;
+
+impl EnemyAi for AggressiveAi {
+    fn choose(&self, battle: &BattleState, _side: Turn) -> Action {
+        best_ready(battle.acting(), is_offensive).unwrap_or(Action::Attack)
+    }
+}
+
+/// Shields or heals itself once hurt and turtles when close to death.
+pub struct CautiousAi;
+
+impl EnemyAi for CautiousAi {
+    fn choose(&self, battle: &BattleState, side: Turn) -> Action {
+        let me = battle.acting();
+        let hp = hp_percent(me);
+        if hp <= 50 {
+            if let Some(action) = best_ready(me, is_defensive) {
+                return action;
+            }
+        }
+        if hp <= 30 && history(battle, side).next() != Some(Action::Defend) {
+            return Action::Defend;
+        }
+        best_ready(me, is_offensive).unwrap_or(Action::Attack)
+    }
+}
+
+/// Watches the opponent's recent moves. Saves guard-piercing abilities for
+/// when the opponent is defending and punishes habitual turtling with them;
+/// otherwise refuses to waste big hits into a raised guard.
+pub struct PatternReaderAi;
+
+impl EnemyAi for PatternReaderAi {
+    fn choose(&self, battle: &BattleState, side: Turn) -> Action {
+        let me = battle.acting();
+        let recent_defends = history(battle, side.opponent())
+            .take(3)
+            .filter(|a| *a == Action::Defend)
+            .count();
+
+        if battle.fighter(battle.target_of(side)).defending {
+            if let Some(action) = best_ready(me, pierces) {
+                return action;
+            }
+            if let Some(action) = best_ready(me, is_defensive) {
+                return action;
+            }
+            // Repeat turtling: mirror it so the opponent's guard buys nothing
+            if recent_defends >= 2 {
+                return Action::Defend;
+            }
+            return Action::Attack;
+        }
+
+        // Hold piercing moves back for an opponent who keeps defending
+        let keep_piercing = recent_defends >= 1;
+        best_ready(me, |a| is_offensive(a) && !(keep_piercing && pierces(a)))
+            .unwrap_or(Action::Attack)
+    }
+}
+
+/// Simulates each candidate action a few turns ahead on forked battles
+/// (assuming a greedy opponent) and picks the one with the best outcome.
+pub struct LookaheadAi {
+    /// Exchanges simulated after the candidate action.
+    pub depth: u32,
+}
+
+const LOOKAHEAD_SAMPLES: u64 = 3;
+
+impl LookaheadAi {
+    fn evaluate(&self, battle: &BattleState, side: Turn, action: Action, salt: u64) -> i32 {
+        let mut sim = battle.fork(salt);
+        sim.act(action);
+        // Both sides play greedily from here on: their strongest ready
+        // damaging ability, otherwise a plain attack
+        for _ in 0..self.depth {
+            if sim.result.is_some() {
+                break;
+            }
+            if sim.turn != side {
+                sim.act(AggressiveAi.choose(&sim, sim.turn));
+            }
+            while sim.result.is_none() && sim.turn == side {
+                sim.act(AggressiveAi.choose(&sim, side));
+            }
+        }
+        match &sim.result {
+            Some(result) if result.winner() == side => 1000,
+            Some(_) => -1000,
+            None => team_hp_percent(sim.side(side)) - team_hp_percent(sim.side(side.opponent())),
+        }
+    }
+}
+
+impl EnemyAi for LookaheadAi {
+    fn choose(&self, battle: &BattleState, side: Turn) -> Action {
+        let mut candidates = vec![Action::Attack, Action::Defend];
+        candidates.extend(
+            battle
+                .acting()
+                .ready_abilities()
+                .map(|(i, _)| Action::Ability(i as u8)),
+        );
+
+        let mut best = (i32::MIN, Action::Attack);
+        for &action in &candidates {
+            // Same salts for every candidate so they face the same rolls
+            let total: i32 = (1..=LOOKAHEAD_SAMPLES)
+                .map(|salt| self.evaluate(battle, side, action, salt))
+                .sum();
+            if total > best.0 {
+                best = (total, action);
+            }
+        }
+        best.1
+    }
+}
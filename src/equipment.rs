@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content;
+use crate::locale::Locale;
+
+/// Where a piece of equipment is worn. Each character has one of each.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Slot {
+    Weapon,
+    Armor,
+    Trinket,
+}
+
+impl Slot {
+    pub fn all() -> [Slot; 3] {
+        [Slot::Weapon, Slot::Armor, Slot::Trinket]
+    }
+
+    pub fn label(self, locale: Locale) -> String {
+        locale.t(match self {
+            Slot::Weapon => "slot.weapon",
+            Slot::Armor => "slot.armor",
+            Slot::Trinket => "slot.trinket",
+        })
+    }
+}
+
+/// How a fighter's weapon is drawn on its stick figure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeaponStyle {
+    #[default]
+    Sword,
+    Axe,
+    Hammer,
+}
+
+/// Always-on effect granted by a piece of equipment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Passive {
+    /// Heal `percent`% of the damage each hit deals.
+    Lifesteal { percent: u32 },
+    /// Reflect `percent`% of the damage taken from a hit onto the attacker.
+    /// Never finishes the attacker off.
+    Thorns { percent: u32 },
+    /// Heal `hp` at the start of each turn.
+    Regeneration { hp: u32 },
+}
+
+impl Passive {
+    pub fn description(self, locale: Locale) -> String {
+        match self {
+            Passive::Lifesteal { percent } => {
+                locale.format("passive.lifesteal", &[("percent", percent.into())])
+            }
+            Passive::Thorns { percent } => {
+                locale.format("passive.thorns", &[("percent", percent.into())])
+            }
+            Passive::Regeneration { hp } => {
+                locale.format("passive.regeneration", &[("hp", hp.into())])
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Equipment {
+    pub id: String,
+    pub slot: Slot,
+    #[serde(default)]
+    pub hp: u32,
+    #[serde(default)]
+    pub atk: u32,
+    #[serde(default)]
+    pub def: u32,
+    #[serde(default)]
+    pub passive: Option<Passive>,
+    /// How a weapon is drawn (None for armor and trinkets).
+    #[serde(default)]
+    pub style: Option<WeaponStyle>,
+    /// Level a character must reach before it can wear this.
+    pub unlock_level: u32,
+}
+
+impl Equipment {
+    pub fn all() -> Vec<Self> {
+        content::get().equipment.clone()
+    }
+
+    pub fn by_id(id: &str) -> Option<Self> {
+        content::get()
+            .equipment
+            .iter()
+            .find(|e| e.id == id)
+            .cloned()
+    }
+
+    pub fn name(&self, locale: Locale) -> String {
+        locale.t(&content::text_key("equipment", &self.id, "name"))
+    }
+
+    /// Equipment for `slot` that a character at `level` can wear.
+    pub fn unlocked(slot: Slot, level: u32) -> Vec<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|e| e.slot == slot && e.unlock_level <= level)
+            .collect()
+    }
+
+    /// Short description of what this adds, e.g. "+3 ATK · Heal 20% of
+    /// damage dealt".
+    pub fn summary(&self, locale: Locale) -> String {
+        let mut parts = Vec::new();
+        for (amount, stat) in [
+            (self.hp, "stat.hp"),
+            (self.atk, "stat.atk"),
+            (self.def, "stat.def"),
+        ] {
+            if amount > 0 {
+                parts.push(locale.format(
+                    "stats.bonus",
+                    &[("amount", amount.into()), ("stat", locale.t(stat).into())],
+                ));
+            }
+        }
+        if let Some(passive) = self.passive {
+            parts.push(passive.description(locale));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// What a minted character has equipped: an equipment id per slot.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Loadout {
+    pub weapon: Option<String>,
+    pub armor: Option<String>,
+    pub trinket: Option<String>,
+}
+
+impl Loadout {
+    pub fn get(&self, slot: Slot) -> Option<&String> {
+        match slot {
+            Slot::Weapon => self.weapon.as_ref(),
+            Slot::Armor => self.armor.as_ref(),
+            Slot::Trinket => self.trinket.as_ref(),
+        }
+    }
+
+    /// Put `id` in `slot`, or empty it with None.
+    pub fn set(&mut self, slot: Slot, id: Option<String>) {
+        match slot {
+            Slot::Weapon => self.weapon = id,
+            Slot::Armor => self.armor = id,
+            Slot::Trinket => self.trinket = id,
+        }
+    }
+
+    /// Equipped ids in slot order.
+    pub fn ids(&self) -> Vec<String> {
+        Slot::all()
+            .into_iter()
+            .filter_map(|slot| self.get(slot).cloned())
+            .collect()
+    }
+}
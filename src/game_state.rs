@@ -1,6 +1,20 @@
 use bytemuck::{Pod, Zeroable};
 use serde::{Deserialize, Serialize};
 
+use crate::abilities::{Ability, AbilityEffect, AbilitySlot, AbilityTarget};
+use crate::affinity::Affinity;
+use crate::battle_event::{BattleEvent, HealSource};
+use crate::combo::{Combo, ComboKind};
+use crate::content;
+use crate::enemy_ai;
+use crate::equipment::{Equipment, Loadout, Passive, WeaponStyle};
+use crate::initiative::{self, CombatantId, TurnQueue};
+use crate::items::{self, Inventory, ItemKind};
+use crate::locale::Locale;
+use crate::progression;
+use crate::score::{self, ScoreBreakdown};
+use crate::status::{self, StatusEffect};
+
 /// On-chain health/battle state.
 /// Must be #[repr(C)] + Pod + Zeroable with simple primitive types so
 /// bytemuck::bytes_of() produces the exact layout the Mojo SDK writes on-chain.
@@ -18,11 +32,12 @@ pub struct PlayerState {
 impl PlayerState {
     /// Build from a running BattleState snapshot for on-chain storage.
     pub fn from_battle(battle: &BattleState) -> Self {
+        let player = battle.player();
         Self {
-            health: battle.player_hp.max(0) as u16,
-            max_health: battle.player_max_hp as u16,
-            attack: battle.player_atk as u16,
-            defense: battle.player_def as u16,
+            health: player.hp.max(0) as u16,
+            max_health: player.max_hp as u16,
+            attack: player.atk as u16,
+            defense: player.def as u16,
             score: battle.score as u32,
         }
     }
@@ -31,6 +46,17 @@ impl PlayerState {
     pub fn serialize_state(&self) -> Vec<u8> {
         bytemuck::bytes_of(self).to_vec()
     }
+
+    /// Decode a state account's data. The state is the trailing
+    /// `size_of::<PlayerState>()` bytes, after any account header.
+    pub fn from_account_data(data: &[u8]) -> Option<Self> {
+        let start = data.len().checked_sub(std::mem::size_of::<Self>())?;
+        Some(bytemuck::pod_read_unaligned(&data[start..]))
+    }
+}
+
+fn default_speed() -> u32 {
+    initiative::DEFAULT_SPEED
 }
 
 // Client-side game state (richer, for UI)
@@ -40,80 +66,464 @@ pub struct CharacterTemplate {
     pub hp: u32,
     pub atk: u32,
     pub def: u32,
+    /// Initiative gain per tick; faster characters act more often.
+    #[serde(default = "default_speed")]
+    pub speed: u32,
+    /// HSL hue the character is drawn in.
+    #[serde(default)]
+    pub hue: u32,
+    #[serde(default)]
+    pub affinity: Affinity,
+    /// Written for custom heroes; empty for built-in characters, whose
+    /// text is in the catalogues (see `description()`).
+    #[serde(default)]
     pub description: String,
+    /// Ability ids (see `data/content.json`) this character can use in battle.
+    #[serde(default)]
+    pub abilities: Vec<String>,
+    /// Equipment ids (see `data/content.json`) worn into battle. Their stat
+    /// bonuses are already counted in `hp`, `atk` and `def`.
+    #[serde(default)]
+    pub equipment: Vec<String>,
+}
+
+/// A fighter's description: `authored` if it has one, otherwise the
+/// catalogue's text for built-in content.
+fn fighter_description(name: &str, authored: &str, locale: Locale) -> String {
+    if authored.is_empty() {
+        locale.t(&content::text_key("fighter", name, "description"))
+    } else {
+        authored.to_string()
+    }
 }
 
 impl CharacterTemplate {
+    pub fn description(&self, locale: Locale) -> String {
+        fighter_description(&self.name, &self.description, locale)
+    }
+
+    /// Resolve this character's ability ids, skipping unknown ones.
+    pub fn ability_list(&self) -> Vec<Ability> {
+        self.abilities
+            .iter()
+            .filter_map(|id| Ability::by_id(id))
+            .collect()
+    }
+
+    /// This character's stats after growing to `level`.
+    pub fn at_level(&self, level: u32) -> Self {
+        let gained = level.clamp(1, progression::MAX_LEVEL) - 1;
+        Self {
+            hp: self.hp + gained * progression::HP_PER_LEVEL,
+            atk: self.atk + gained * progression::ATK_PER_LEVEL,
+            def: self.def + gained * progression::DEF_PER_LEVEL,
+            speed: self.speed + gained / progression::LEVELS_PER_SPEED,
+            ..self.clone()
+        }
+    }
+
+    /// Resolve this character's equipment ids, skipping unknown ones.
+    pub fn equipment_list(&self) -> Vec<Equipment> {
+        self.equipment
+            .iter()
+            .filter_map(|id| Equipment::by_id(id))
+            .collect()
+    }
+
+    /// This character wearing `loadout`: stat bonuses added and the ids
+    /// recorded for passives. Apply once, after `at_level`.
+    pub fn equip(&self, loadout: &Loadout) -> Self {
+        let mut equipped = Self {
+            equipment: loadout.ids(),
+            ..self.clone()
+        };
+        for item in equipped.equipment_list() {
+            equipped.hp += item.hp;
+            equipped.atk += item.atk;
+            equipped.def += item.def;
+        }
+        equipped
+    }
+
     pub fn all() -> Vec<Self> {
-        vec![
-            Self {
-                name: "Freya".into(),
-                hp: 100,
-                atk: 18,
-                def: 12,
-                description: "Norse warrior goddess".into(),
-            },
-            Self {
-                name: "Odin".into(),
-                hp: 120,
-                atk: 15,
-                def: 15,
-                description: "Allfather of wisdom".into(),
-            },
-            Self {
-                name: "Thor".into(),
-                hp: 110,
-                atk: 22,
-                def: 8,
-                description: "God of thunder".into(),
-            },
-            Self {
-                name: "Loki".into(),
-                hp: 80,
-                atk: 25,
-                def: 5,
-                description: "Trickster shapeshifter".into(),
-            },
-            Self {
-                name: "Hel".into(),
-                hp: 90,
-                atk: 20,
-                def: 10,
-                description: "Queen of the dead".into(),
-            },
-            Self {
-                name: "Tyr".into(),
-                hp: 130,
-                atk: 14,
-                def: 18,
-                description: "God of war and law".into(),
-            },
-        ]
+        content::get().characters.clone()
+    }
+}
+
+/// How an enemy fights. Drives which AI plays its turns.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum EnemyBehaviour {
+    Aggressive,
+    Cautious,
+    Cunning,
+    Tactician,
+}
+
+impl EnemyBehaviour {
+    pub fn label(self, locale: Locale) -> String {
+        locale.t(match self {
+            EnemyBehaviour::Aggressive => "behaviour.aggressive",
+            EnemyBehaviour::Cautious => "behaviour.cautious",
+            EnemyBehaviour::Cunning => "behaviour.cunning",
+            EnemyBehaviour::Tactician => "behaviour.tactician",
+        })
+    }
+}
+
+/// Opponent definition, the enemy-side counterpart of `CharacterTemplate`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnemyTemplate {
+    pub name: String,
+    pub hp: u32,
+    pub atk: u32,
+    pub def: u32,
+    #[serde(default = "default_speed")]
+    pub speed: u32,
+    /// HSL hue of the enemy's stick figure.
+    pub hue: u32,
+    #[serde(default)]
+    pub affinity: Affinity,
+    pub behaviour: EnemyBehaviour,
+    /// Set for rivals; empty for built-in enemies, whose text is in the
+    /// catalogues (see `description()`).
+    #[serde(default)]
+    pub description: String,
+    /// Status inflicted on the player by every attack that lands.
+    #[serde(default)]
+    pub on_hit: Option<StatusEffect>,
+    /// Ability ids the enemy AI may use.
+    #[serde(default)]
+    pub abilities: Vec<String>,
+    /// Boss phases, in the order they trigger.
+    #[serde(default)]
+    pub phases: Vec<EnemyPhase>,
+}
+
+/// A boss phase, entered once when the enemy drops to `below_percent` of
+/// its max HP.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnemyPhase {
+    pub below_percent: u32,
+    #[serde(default)]
+    pub atk_bonus: u32,
+    #[serde(default)]
+    pub def_bonus: u32,
+    /// Switch to a different AI for the rest of the fight.
+    #[serde(default)]
+    pub behaviour: Option<EnemyBehaviour>,
+    /// Whether the boss says a line as the phase starts.
+    #[serde(default)]
+    pub dialogue: bool,
+    /// Names of minions called into the fight.
+    #[serde(default)]
+    pub summon: Vec<String>,
+}
+
+impl Default for EnemyTemplate {
+    fn default() -> Self {
+        Self::all().remove(0)
+    }
+}
+
+impl EnemyTemplate {
+    /// A player character standing in as the opponent (hot-seat battles).
+    pub fn from_character(character: &CharacterTemplate, hue: u32) -> Self {
+        Self {
+            name: character.name.clone(),
+            hp: character.hp,
+            atk: character.atk,
+            def: character.def,
+            speed: character.speed,
+            hue,
+            affinity: character.affinity,
+            behaviour: EnemyBehaviour::Aggressive,
+            description: character.description.clone(),
+            on_hit: None,
+            abilities: character.abilities.clone(),
+            phases: vec![],
+        }
+    }
+
+    pub fn ability_list(&self) -> Vec<Ability> {
+        self.abilities
+            .iter()
+            .filter_map(|id| Ability::by_id(id))
+            .collect()
+    }
+
+    /// The name shown for this enemy: the catalogue's for built-in enemies,
+    /// as written for rivals and hot-seat opponents.
+    pub fn name(&self, locale: Locale) -> String {
+        let key = content::text_key("enemy", &self.name, "name");
+        if Locale::has(&key) {
+            locale.t(&key)
+        } else {
+            self.name.clone()
+        }
+    }
+
+    pub fn description(&self, locale: Locale) -> String {
+        fighter_description(&self.name, &self.description, locale)
+    }
+
+    /// Catalogue keys of the log message and the boss's line for phase
+    /// `index` of the boss called `name`.
+    pub fn phase_keys(name: &str, index: usize) -> (String, String) {
+        let phase = format!("phase_{}", index + 1);
+        (
+            content::text_key("boss", name, &format!("{}.message", phase)),
+            content::text_key("boss", name, &format!("{}.dialogue", phase)),
+        )
+    }
+
+    /// What the log announces as phase `index` starts, and the boss's line
+    /// if it says one.
+    pub fn phase_text(&self, index: usize, locale: Locale) -> (String, Option<String>) {
+        let (message, dialogue) = Self::phase_keys(&self.name, index);
+        let says = self.phases.get(index).is_some_and(|p| p.dialogue);
+        (locale.t(&message), says.then(|| locale.t(&dialogue)))
+    }
+
+    pub fn all() -> Vec<Self> {
+        content::get().enemies.clone()
+    }
+
+    /// The minion called `name`, for a boss phase to summon.
+    pub fn minion(name: &str) -> Option<Self> {
+        content::get()
+            .minions
+            .iter()
+            .find(|e| e.name == name)
+            .cloned()
+    }
+}
+
+/// Largest party a side can bring into a battle.
+pub const MAX_PARTY: usize = 3;
+
+/// Fighters per side on the field at once. The rest of a party waits in
+/// reserve until swapped in or until a teammate is knocked out.
+pub const FIELD_SIZE: usize = 2;
+
+/// One fighter in a battle. Both sides are made of these whether a person
+/// or an AI is playing them.
+#[derive(Clone, Debug)]
+pub struct Combatant {
+    pub name: String,
+    pub hp: i32,
+    pub max_hp: i32,
+    pub atk: u32,
+    pub def: u32,
+    pub spd: u32,
+    pub affinity: Affinity,
+    /// Random damage added to each hit, in `0..variance`.
+    pub variance: u32,
+    pub abilities: Vec<AbilitySlot>,
+    pub status: Vec<StatusEffect>,
+    /// Set by an evade effect; the next incoming attack misses.
+    pub evading: bool,
+    /// Guard raised this turn; halves the next incoming hit.
+    pub defending: bool,
+    /// Status inflicted on whoever this fighter's attacks land on.
+    pub on_hit: Option<StatusEffect>,
+    /// The AI playing this fighter, or None when a person is at the controls.
+    pub ai: Option<EnemyBehaviour>,
+    /// Always-on effects from equipment.
+    pub passives: Vec<Passive>,
+    /// How this fighter's weapon is drawn.
+    pub weapon: WeaponStyle,
+    /// This fighter's recent moves, for combos.
+    pub combo: Combo,
+}
+
+impl Combatant {
+    /// A player character, controlled by a person.
+    pub fn from_character(character: &CharacterTemplate) -> Self {
+        let equipment = character.equipment_list();
+        Self {
+            name: character.name.clone(),
+            hp: character.hp as i32,
+            max_hp: character.hp as i32,
+            atk: character.atk,
+            def: character.def,
+            spd: character.speed,
+            affinity: character.affinity,
+            variance: 5,
+            abilities: ability_slots(character.ability_list()),
+            status: Vec::new(),
+            evading: false,
+            defending: false,
+            on_hit: None,
+            ai: None,
+            passives: equipment.iter().filter_map(|e| e.passive).collect(),
+            weapon: equipment.iter().find_map(|e| e.style).unwrap_or_default(),
+            combo: Combo::default(),
+        }
+    }
+
+    /// An enemy, played by the AI its behaviour maps to.
+    pub fn from_enemy(enemy: &EnemyTemplate) -> Self {
+        Self {
+            name: enemy.name.clone(),
+            hp: enemy.hp as i32,
+            max_hp: enemy.hp as i32,
+            atk: enemy.atk,
+            def: enemy.def,
+            spd: enemy.speed,
+            affinity: enemy.affinity,
+            variance: 4,
+            abilities: ability_slots(enemy.ability_list()),
+            status: Vec::new(),
+            evading: false,
+            defending: false,
+            on_hit: enemy.on_hit,
+            ai: Some(enemy.behaviour),
+            passives: Vec::new(),
+            weapon: WeaponStyle::default(),
+            combo: Combo::default(),
+        }
+    }
+
+    /// Ability slots that are off cooldown.
+    pub fn ready_abilities(&self) -> impl Iterator<Item = (usize, &Ability)> {
+        self.abilities
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.ready())
+            .map(|(i, s)| (i, &s.ability))
+    }
+}
+
+/// One side of a battle: a party of one to `MAX_PARTY` fighters, the first
+/// of which leads.
+#[derive(Clone, Debug)]
+pub struct Team {
+    pub members: Vec<Combatant>,
+    /// Members fighting right now (indices into `members`), at most
+    /// `FIELD_SIZE`. Knocked-out members leave the field.
+    pub field: Vec<usize>,
+    /// Opposing member this side's attacks and abilities go to.
+    pub focus: usize,
+    /// Items left, shared by the whole party, including any victory drop.
+    pub inventory: Inventory,
+}
+
+impl Team {
+    fn new(members: Vec<Combatant>) -> Self {
+        let field = (0..members.len().min(FIELD_SIZE)).collect();
+        Self {
+            members,
+            field,
+            focus: 0,
+            inventory: Inventory::default(),
+        }
+    }
+
+    /// The first member; a 1v1 battle is all about them.
+    pub fn lead(&self) -> &Combatant {
+        &self.members[0]
+    }
+
+    /// Members still standing who are waiting off the field.
+    pub fn reserves(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.members.len()).filter(|m| self.members[*m].hp > 0 && !self.field.contains(m))
+    }
+
+    /// Every member has been knocked out.
+    pub fn defeated(&self) -> bool {
+        self.members.iter().all(|c| c.hp <= 0)
+    }
+
+    /// Played by people rather than an AI.
+    pub fn is_human(&self) -> bool {
+        self.members.iter().all(|c| c.ai.is_none())
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct BattleState {
-    pub player_hp: i32,
-    pub player_max_hp: i32,
-    pub enemy_hp: i32,
-    pub enemy_max_hp: i32,
-    pub player_atk: u32,
-    pub player_def: u32,
-    pub enemy_atk: u32,
-    pub enemy_def: u32,
+    /// The opposing party this battle was built from, then any enemies
+    /// summoned since. The first one leads, and its boss phases are the
+    /// ones that trigger.
+    pub enemies: Vec<EnemyTemplate>,
+    /// Number of the lead enemy's phases triggered so far.
+    pub enemy_phase: usize,
+    /// How many of `enemies` were summoned mid-fight (the last ones).
+    pub summoned: usize,
+    /// Both parties, indexed by `Turn::index`.
+    pub sides: [Team; 2],
+    /// HP the player's lead started on (below max when carried over in a
+    /// campaign).
+    pub starting_hp: i32,
     pub turn: Turn,
-    pub log: Vec<LogEntry>,
+    /// Member of `turn`'s party who is acting.
+    pub actor: usize,
+    /// Who acts next, driven by each fighter's speed.
+    pub initiative: TurnQueue,
+    /// Everything that has happened, in order. `log()` writes it out.
+    pub events: Vec<BattleEvent>,
+    /// Outcome from the first side's point of view.
     pub result: Option<BattleResult>,
+    /// `breakdown.total()`, kept up to date after every action.
     pub score: u32,
+    pub breakdown: ScoreBreakdown,
+    /// Seed the battle's RNG was started from. Re-running the same actions
+    /// against `BattleState::team_battle(party, enemies, seed)` reproduces the
+    /// fight.
+    pub seed: u64,
+    /// Every action taken so far by either side, in order (see
+    /// `replay::BattleReplay`).
+    pub actions: Vec<(Turn, Action)>,
+    /// Who the most recent strike of the current action was aimed at and
+    /// how it landed (Damage, Crit, Miss...), for the UI's hit animation.
+    /// Taken from the last `BattleEvent::impact` of the action.
+    pub last_impact: Option<(CombatantId, LogKind)>,
+    /// Items the player brought in; a replay starts from these.
+    pub starting_inventory: Inventory,
+    /// Item dropped by the defeated enemy.
+    pub loot: Option<ItemKind>,
+    rng: BattleRng,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// What a side does with its turn, whether picked by a person or an `EnemyAi`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+    Attack,
+    Defend,
+    /// Index into the acting fighter's `Combatant::abilities`.
+    Ability(u8),
+    Item(ItemKind),
+    /// Aim the side's attacks at this opposing member. Free: the same
+    /// fighter still has to act.
+    Target(u8),
+    /// Send this reserve member in for the acting fighter. Costs the turn.
+    Swap(u8),
+}
+
+/// Whose turn it is; also used to name a side of the battle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Turn {
     Player,
     Enemy,
 }
 
+impl Turn {
+    pub fn opponent(self) -> Turn {
+        match self {
+            Turn::Player => Turn::Enemy,
+            Turn::Enemy => Turn::Player,
+        }
+    }
+
+    /// Position of this side in `BattleState::sides`.
+    pub fn index(self) -> usize {
+        match self {
+            Turn::Player => 0,
+            Turn::Enemy => 1,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct LogEntry {
     pub message: String,
@@ -125,6 +535,30 @@ pub enum LogKind {
     Damage,
     Heal,
     Info,
+    /// A critical hit landed.
+    Crit,
+    /// An attack was dodged or evaded.
+    Miss,
+}
+
+/// Chance (percent) that a victory drops an item.
+const LOOT_CHANCE: u32 = 60;
+
+/// Damage multiplier (percent) of a critical hit.
+const CRIT_PERCENT: u32 = 150;
+
+/// Stick figure hue for the second player in a hot-seat battle.
+pub const RIVAL_HUE: u32 = 200;
+
+/// Chance (percent) that a hit from an attacker with `atk` is critical.
+fn crit_chance(atk: u32) -> u32 {
+    (5 + atk / 4).min(25)
+}
+
+/// Chance (percent) that an attack lands; the faster side hits more
+/// reliably and dodges more often.
+fn hit_chance(attacker_spd: u32, defender_spd: u32) -> u32 {
+    (90 + 2 * attacker_spd as i32 - 2 * defender_spd as i32).clamp(70, 98) as u32
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -133,90 +567,987 @@ pub enum BattleResult {
     Defeat,
 }
 
+impl BattleResult {
+    /// The side that won.
+    pub fn winner(&self) -> Turn {
+        match self {
+            BattleResult::Victory => Turn::Player,
+            BattleResult::Defeat => Turn::Enemy,
+        }
+    }
+}
+
+fn ability_slots(abilities: Vec<Ability>) -> Vec<AbilitySlot> {
+    abilities
+        .into_iter()
+        .map(|ability| AbilitySlot {
+            ability,
+            cooldown_left: 0,
+        })
+        .collect()
+}
+
 impl BattleState {
-    pub fn new(player: &CharacterTemplate) -> Self {
-        let enemy_hp = 80 + (player.hp / 2);
-        Self {
-            player_hp: player.hp as i32,
-            player_max_hp: player.hp as i32,
-            enemy_hp: enemy_hp as i32,
-            enemy_max_hp: enemy_hp as i32,
-            player_atk: player.atk,
-            player_def: player.def,
-            enemy_atk: 12,
-            enemy_def: 8,
-            turn: Turn::Player,
-            log: vec![LogEntry {
-                message: "Battle begins!".into(),
-                kind: LogKind::Info,
-            }],
+    /// Party against party from a known seed (pass `random_seed()` for a
+    /// fresh fight). Both slices hold one to `MAX_PARTY` fighters, leader
+    /// first.
+    pub fn team_battle(party: &[CharacterTemplate], enemies: &[EnemyTemplate], seed: u64) -> Self {
+        let sides = [
+            Team::new(party.iter().map(Combatant::from_character).collect()),
+            Team::new(enemies.iter().map(Combatant::from_enemy).collect()),
+        ];
+        Self::start(sides, enemies.to_vec(), seed)
+    }
+
+    /// Local two-player battle: both characters are controlled by people
+    /// taking turns at the same screen.
+    pub fn hot_seat(first: &CharacterTemplate, second: &CharacterTemplate, seed: u64) -> Self {
+        let sides = [
+            Team::new(vec![Combatant::from_character(first)]),
+            Team::new(vec![Combatant::from_character(second)]),
+        ];
+        Self::start(
+            sides,
+            vec![EnemyTemplate::from_character(second, RIVAL_HUE)],
+            seed,
+        )
+    }
+
+    fn start(sides: [Team; 2], enemies: Vec<EnemyTemplate>, seed: u64) -> Self {
+        let mut initiative = TurnQueue::new();
+        for (side, team) in [Turn::Player, Turn::Enemy].into_iter().zip(&sides) {
+            for &m in &team.field {
+                initiative.add(CombatantId::new(side, m), team.members[m].spd);
+            }
+        }
+        let first = initiative
+            .next()
+            .unwrap_or(CombatantId::new(Turn::Player, 0));
+
+        let difficulty = score::difficulty_percent(&sides[0].members, &sides[1].members);
+        let mut battle = Self {
+            enemies,
+            enemy_phase: 0,
+            summoned: 0,
+            starting_hp: sides[0].lead().hp,
+            sides,
+            turn: first.side,
+            actor: first.slot,
+            initiative,
+            events: Vec::new(),
             result: None,
             score: 0,
+            breakdown: ScoreBreakdown::new(difficulty),
+            seed,
+            actions: Vec::new(),
+            last_impact: None,
+            starting_inventory: Inventory::default(),
+            loot: None,
+            rng: BattleRng::new(seed),
+        };
+        battle.emit(BattleEvent::BattleStarted { first });
+        battle.emit(BattleEvent::TurnStarted { id: first });
+        battle
+    }
+
+    /// Start the player's lead on `hp` instead of full health.
+    pub fn with_player_hp(mut self, hp: i32) -> Self {
+        let player = &mut self.sides[0].members[0];
+        player.hp = hp.clamp(1, player.max_hp);
+        self.starting_hp = player.hp;
+        self
+    }
+
+    /// Carry `inventory` into the battle.
+    pub fn with_inventory(mut self, inventory: Inventory) -> Self {
+        self.starting_inventory = inventory.clone();
+        self.sides[0].inventory = inventory;
+        self
+    }
+
+    /// Copy of this battle with an independent RNG stream, for AI lookahead.
+    /// The fork can't peek at the real battle's upcoming rolls.
+    pub fn fork(&self, salt: u64) -> Self {
+        let mut sim = self.clone();
+        sim.rng = BattleRng::new(self.rng.state ^ salt.wrapping_mul(0xA24B_AED4_963E_E407));
+        sim
+    }
+
+    /// The lead opponent.
+    pub fn enemy(&self) -> &EnemyTemplate {
+        &self.enemies[0]
+    }
+
+    /// The opposing party as the battle started, without summons.
+    pub fn starting_enemies(&self) -> &[EnemyTemplate] {
+        &self.enemies[..self.enemies.len() - self.summoned]
+    }
+
+    pub fn side(&self, side: Turn) -> &Team {
+        &self.sides[side.index()]
+    }
+
+    fn side_mut(&mut self, side: Turn) -> &mut Team {
+        &mut self.sides[side.index()]
+    }
+
+    pub fn fighter(&self, id: CombatantId) -> &Combatant {
+        &self.side(id.side).members[id.slot]
+    }
+
+    /// The name shown for `id`. Enemies are named through their template,
+    /// so built-in ones are translated.
+    pub fn fighter_name(&self, id: CombatantId, locale: Locale) -> String {
+        match (id.side, self.enemies.get(id.slot)) {
+            (Turn::Enemy, Some(enemy)) => enemy.name(locale),
+            _ => self.fighter(id).name.clone(),
         }
     }
 
-    pub fn player_attack(&mut self) -> u32 {
-        let base = self.player_atk as i32 - (self.enemy_def as i32 / 2);
-        let variance = simple_random(5) as i32;
-        let damage = (base + variance).max(1) as u32;
-        self.enemy_hp = (self.enemy_hp - damage as i32).max(0);
-        self.log.push(LogEntry {
-            message: format!("You deal {} damage!", damage),
-            kind: LogKind::Damage,
-        });
-        self.score += damage;
-        if self.enemy_hp <= 0 {
-            self.result = Some(BattleResult::Victory);
-            self.log.push(LogEntry {
-                message: "VICTORY! Enemy defeated!".into(),
-                kind: LogKind::Info,
-            });
+    /// Names of `side`'s members joined for the log ("Freya & Thor").
+    pub fn names(&self, side: Turn, locale: Locale) -> String {
+        (0..self.side(side).members.len())
+            .map(|slot| self.fighter_name(CombatantId::new(side, slot), locale))
+            .collect::<Vec<_>>()
+            .join(" & ")
+    }
+
+    fn fighter_mut(&mut self, id: CombatantId) -> &mut Combatant {
+        &mut self.side_mut(id.side).members[id.slot]
+    }
+
+    /// The fighter whose turn it is.
+    pub fn acting_id(&self) -> CombatantId {
+        CombatantId::new(self.turn, self.actor)
+    }
+
+    pub fn acting(&self) -> &Combatant {
+        self.fighter(self.acting_id())
+    }
+
+    /// Lead of the first side: the player, or player 1 in a hot-seat battle.
+    pub fn player(&self) -> &Combatant {
+        self.side(Turn::Player).lead()
+    }
+
+    /// The opposing fighter `side`'s attacks land on: its focus while that
+    /// member is on the field, otherwise the first one that is.
+    pub fn target_of(&self, side: Turn) -> CombatantId {
+        let foes = self.side(side.opponent());
+        let focus = self.side(side).focus;
+        let slot = if foes.field.contains(&focus) {
+            focus
         } else {
-            self.turn = Turn::Enemy;
+            foes.field.first().copied().unwrap_or(0)
+        };
+        CombatantId::new(side.opponent(), slot)
+    }
+
+    /// Both sides are played by people.
+    pub fn is_hot_seat(&self) -> bool {
+        self.sides.iter().all(Team::is_human)
+    }
+
+    /// Whether the fighter to move is waiting on a person's input.
+    pub fn awaiting_input(&self) -> bool {
+        self.result.is_none() && self.acting().ai.is_none()
+    }
+
+    /// Take `action` for the fighter whose turn it is. Returns false (no
+    /// turn is consumed) if the battle is over or the action isn't possible:
+    /// an ability still cooling down, an item that has run out, or a target
+    /// or swap to someone who isn't there.
+    pub fn act(&mut self, action: Action) -> bool {
+        if self.result.is_some() {
+            return false;
         }
-        damage
+        let side = self.turn;
+        let actor = self.acting_id();
+        match action {
+            Action::Attack | Action::Defend => {}
+            Action::Ability(slot) => {
+                let ready = self
+                    .fighter(actor)
+                    .abilities
+                    .get(slot as usize)
+                    .is_some_and(|s| s.ready());
+                if !ready {
+                    return false;
+                }
+            }
+            Action::Item(kind) => {
+                if !kind.usable() || !self.side_mut(side).inventory.take(kind) {
+                    return false;
+                }
+            }
+            Action::Target(member) => {
+                if !self
+                    .side(side.opponent())
+                    .field
+                    .contains(&(member as usize))
+                {
+                    return false;
+                }
+                self.side_mut(side).focus = member as usize;
+                self.actions.push((side, action));
+                return true;
+            }
+            Action::Swap(member) => {
+                if !self.side(side).reserves().any(|m| m == member as usize) {
+                    return false;
+                }
+            }
+        }
+
+        self.last_impact = None;
+        self.actions.push((side, action));
+        let mut missed = false;
+        match action {
+            Action::Attack => {
+                let target = self.target_of(side);
+                let combo = self.fighter(actor).combo.next_attack();
+                let percent = 100 + combo.map_or(0, ComboKind::bonus_percent);
+                missed = self.strike(actor, target, percent, false, None) == 0;
+                // A combo only counts once its attack connects
+                if let Some(combo) = combo.filter(|_| !missed) {
+                    self.emit(BattleEvent::Combo { id: actor, combo });
+                }
+            }
+            Action::Defend => {
+                self.fighter_mut(actor).defending = true;
+                self.emit(BattleEvent::Defended { id: actor });
+            }
+            Action::Ability(slot) => {
+                self.use_ability(actor, slot as usize);
+            }
+            Action::Item(kind) => self.use_item(actor, kind),
+            Action::Swap(member) => self.swap(actor, member as usize),
+            Action::Target(_) => unreachable!("targeting doesn't take the turn"),
+        }
+        // A miss neither builds nor breaks a combo
+        if self.side(side).is_human() && !missed {
+            self.fighter_mut(actor).combo.record(action);
+        }
+        self.end_turn();
+        self.update_score();
+        true
+    }
+
+    /// Fill in the parts of the breakdown read off the battle as it stands
+    /// and recompute `score`.
+    fn update_score(&mut self) {
+        let party = &self.sides[0].members;
+        let hp: i32 = party.iter().map(|c| c.hp.max(0)).sum();
+        let max: i32 = party.iter().map(|c| c.max_hp).sum();
+        self.breakdown.hp_remaining_percent = (hp * 100 / max.max(1)) as u32;
+        self.breakdown.turns = self
+            .actions
+            .iter()
+            .filter(|(side, action)| *side == Turn::Player && !matches!(action, Action::Target(_)))
+            .count() as u32;
+        self.score = self.breakdown.total();
+    }
+
+    /// The move the AI controlling the fighter to act is about to make,
+    /// after aiming its side at the foe it wants. None when a person is up.
+    pub fn ai_action(&mut self) -> Option<Action> {
+        if self.result.is_some() {
+            return None;
+        }
+        let behaviour = self.acting().ai?;
+        // Every AI goes after whichever foe on the field is closest to falling
+        let foes = self.side(self.turn.opponent());
+        if let Some(&weakest) = foes.field.iter().min_by_key(|&&m| foes.members[m].hp) {
+            self.side_mut(self.turn).focus = weakest;
+        }
+        Some(enemy_ai::for_behaviour(behaviour).choose(self, self.turn))
+    }
+
+    /// Let the AI play the current turn, if an AI controls the fighter to
+    /// move. Returns the action taken.
+    pub fn ai_turn(&mut self) -> Option<Action> {
+        let action = self.ai_action()?;
+        self.act(action).then_some(action)
+    }
+
+    /// Fighters due to act after the current turn, in order.
+    pub fn upcoming_turns(&self, count: usize) -> Vec<CombatantId> {
+        self.initiative.preview(count)
+    }
+
+    /// Record `event`, updating the hit animation and the score from it.
+    fn emit(&mut self, event: BattleEvent) {
+        if let Some(impact) = event.impact() {
+            self.last_impact = Some(impact);
+        }
+        self.breakdown.record(&event);
+        self.events.push(event);
+    }
+
+    /// Whether the log talks to `id` as "you" (a lone human player).
+    pub fn is_you(&self, id: CombatantId) -> bool {
+        id.side == Turn::Player
+            && self.sides[0].members.len() == 1
+            && !self.side(Turn::Enemy).is_human()
+    }
+
+    fn use_item(&mut self, user: CombatantId, kind: ItemKind) {
+        let event = match kind {
+            ItemKind::HealingPotion => {
+                let max = self.fighter(user).max_hp as u32;
+                let healed = self.heal(user, max * items::POTION_HEAL_PERCENT / 100);
+                BattleEvent::PotionDrunk { id: user, healed }
+            }
+            ItemKind::AttackTonic => {
+                let fighter = self.fighter_mut(user);
+                fighter.atk += items::TONIC_ATK_BONUS;
+                BattleEvent::TonicDrunk {
+                    id: user,
+                    atk: fighter.atk,
+                }
+            }
+            ItemKind::SmokeBomb => {
+                self.fighter_mut(user).evading = true;
+                BattleEvent::SmokeBomb { id: user }
+            }
+            ItemKind::Revive => unreachable!("revives are not usable"),
+        };
+        self.emit(event);
     }
 
-    pub fn player_defend(&mut self) {
-        self.log.push(LogEntry {
-            message: "You raise your guard!".into(),
-            kind: LogKind::Info,
+    /// Put reserve `member` on the field in place of `out`.
+    fn swap(&mut self, out: CombatantId, member: usize) {
+        let team = self.side_mut(out.side);
+        if let Some(pos) = team.field.iter().position(|&m| m == out.slot) {
+            team.field[pos] = member;
+        }
+        self.fighter_mut(out).defending = false;
+        self.initiative.remove(out);
+        let incoming = CombatantId::new(out.side, member);
+        self.initiative.add(incoming, self.fighter(incoming).spd);
+        self.emit(BattleEvent::Swapped { out, incoming });
+    }
+
+    /// Resolve every effect of the ability in `slot` for `caster`, putting
+    /// it on cooldown. Returns the damage dealt to the target.
+    fn use_ability(&mut self, caster: CombatantId, slot: usize) -> u32 {
+        let slots = &mut self.fighter_mut(caster).abilities;
+        let ability = slots[slot].ability.clone();
+        slots[slot].cooldown_left = ability.cooldown;
+        self.emit(BattleEvent::AbilityUsed {
+            caster,
+            ability: ability.id.clone(),
         });
-        self.turn = Turn::Enemy;
+
+        let target = match ability.target {
+            AbilityTarget::Enemy => self.target_of(caster.side),
+            AbilityTarget::Caster => caster,
+        };
+        let mut dealt = 0;
+        for effect in &ability.effects {
+            if self.fighter(caster).hp <= 0 || self.fighter(target).hp <= 0 {
+                break;
+            }
+            match *effect {
+                AbilityEffect::Damage { percent, pierce } => {
+                    if target == caster {
+                        let fighter = self.fighter_mut(caster);
+                        let recoil = (fighter.atk * percent / 100).max(1);
+                        fighter.hp = (fighter.hp - recoil as i32).max(0);
+                        self.emit(BattleEvent::Recoil {
+                            id: caster,
+                            amount: recoil,
+                        });
+                    } else {
+                        dealt += self.strike(caster, target, percent, pierce, Some(&ability.id));
+                    }
+                }
+                AbilityEffect::Drain { percent } => {
+                    let amount = self.heal(caster, dealt * percent / 100);
+                    self.emit(BattleEvent::Healed {
+                        target: caster,
+                        amount,
+                        source: HealSource::Drain,
+                    });
+                }
+                AbilityEffect::Heal { percent } => {
+                    let max = self.fighter(target).max_hp;
+                    let amount = self.heal(target, max as u32 * percent / 100);
+                    self.emit(BattleEvent::Healed {
+                        target,
+                        amount,
+                        source: HealSource::Ability(ability.id.clone()),
+                    });
+                }
+                AbilityEffect::Evade => {
+                    self.fighter_mut(caster).evading = true;
+                    self.emit(BattleEvent::Evading { id: caster });
+                }
+                AbilityEffect::Sunder { amount } => {
+                    let fighter = self.fighter_mut(target);
+                    fighter.def = fighter.def.saturating_sub(amount);
+                    let def = fighter.def;
+                    self.emit(BattleEvent::Sundered { target, def });
+                }
+                AbilityEffect::ApplyStatus(effect) => {
+                    status::apply(&mut self.fighter_mut(target).status, effect);
+                    self.emit(BattleEvent::StatusApplied {
+                        target,
+                        effect,
+                        on_hit: false,
+                    });
+                }
+            }
+        }
+        dealt
     }
 
-    pub fn enemy_attack(&mut self, player_defending: bool) -> u32 {
-        let base = self.enemy_atk as i32 - (self.player_def as i32 / 2);
-        let variance = simple_random(4) as i32;
-        let mut damage = (base + variance).max(1) as u32;
-        if player_defending {
+    /// One hit from `attacker` on `defender`: `percent`% of
+    /// (ATK - DEF/2 + variance), min 1. Evasion and the accuracy roll come
+    /// first, then crits, the affinity table, guard and shields; `pierce` ignores DEF and guard
+    /// and can't be dodged. `source` names the ability for the log (None
+    /// for a basic attack). Returns the damage that actually landed.
+    fn strike(
+        &mut self,
+        attacker: CombatantId,
+        defender: CombatantId,
+        percent: u32,
+        pierce: bool,
+        source: Option<&str>,
+    ) -> u32 {
+        let (atk, spread, attacker_spd, on_hit) = {
+            let a = self.fighter(attacker);
+            (a.atk, a.variance, a.spd, a.on_hit)
+        };
+        let (def, defender_spd) = (self.fighter(defender).def, self.fighter(defender).spd);
+
+        let evading = std::mem::take(&mut self.fighter_mut(defender).evading);
+        let dodged =
+            !evading && !pierce && self.rng.below(100) >= hit_chance(attacker_spd, defender_spd);
+        if evading || dodged {
+            // What the hit would have done before its rolls
+            let def = if pierce { 0 } else { def as i32 / 2 };
+            self.emit(BattleEvent::Missed {
+                source: attacker,
+                target: defender,
+                evaded: evading,
+                avoided: ((atk as i32 - def).max(1) as u32 * percent / 100).max(1),
+            });
+            return 0;
+        }
+
+        let def = if pierce { 0 } else { def as i32 / 2 };
+        let variance = self.rng.below(spread) as i32;
+        let crit = self.rng.below(100) < crit_chance(atk);
+        let mut damage = ((atk as i32 - def + variance).max(1) as u32 * percent / 100).max(1);
+        if crit {
+            damage = damage * CRIT_PERCENT / 100;
+        }
+        let effectiveness = self
+            .fighter(attacker)
+            .affinity
+            .against(self.fighter(defender).affinity);
+        damage = (damage * effectiveness.percent() / 100).max(1);
+
+        if !pierce && self.fighter(defender).defending {
+            let avoided = damage - damage / 2;
             damage /= 2;
-            self.log.push(LogEntry {
-                message: format!("Blocked! Enemy deals only {} damage.", damage),
-                kind: LogKind::Heal,
+            self.emit(BattleEvent::Blocked {
+                source: attacker,
+                target: defender,
+                amount: damage,
+                avoided,
+                crit,
+                effectiveness,
             });
         } else {
-            self.log.push(LogEntry {
-                message: format!("Enemy strikes for {} damage!", damage),
-                kind: LogKind::Damage,
+            self.emit(BattleEvent::DamageDealt {
+                source: attacker,
+                target: defender,
+                amount: damage,
+                crit,
+                ability: source.map(str::to_string),
+                effectiveness,
             });
         }
-        self.player_hp = (self.player_hp - damage as i32).max(0);
-        if self.player_hp <= 0 {
-            self.result = Some(BattleResult::Defeat);
-            self.log.push(LogEntry {
-                message: "DEFEAT! You have fallen...".into(),
-                kind: LogKind::Info,
+
+        if damage > 0 {
+            damage = self.absorb_with_shield(defender, damage);
+        }
+        let target = self.fighter_mut(defender);
+        target.hp = (target.hp - damage as i32).max(0);
+        let alive = target.hp > 0;
+
+        if damage > 0 {
+            self.apply_hit_passives(attacker, defender, damage);
+        }
+        if let Some(effect) = on_hit.filter(|_| damage > 0 && alive) {
+            status::apply(&mut self.fighter_mut(defender).status, effect);
+            self.emit(BattleEvent::StatusApplied {
+                target: defender,
+                effect,
+                on_hit: true,
             });
-        } else {
-            self.turn = Turn::Player;
         }
         damage
     }
+
+    /// Equipment passives triggered by a hit of `damage` landing: the
+    /// attacker's lifesteal and the defender's thorns.
+    fn apply_hit_passives(&mut self, attacker: CombatantId, defender: CombatantId, damage: u32) {
+        for passive in self.fighter(attacker).passives.clone() {
+            if let Passive::Lifesteal { percent } = passive {
+                let amount = self.heal(attacker, damage * percent / 100);
+                if amount > 0 {
+                    self.emit(BattleEvent::Healed {
+                        target: attacker,
+                        amount,
+                        source: HealSource::Drain,
+                    });
+                }
+            }
+        }
+        for passive in self.fighter(defender).passives.clone() {
+            if let Passive::Thorns { percent } = passive {
+                let target = self.fighter_mut(attacker);
+                let amount = (damage * percent / 100).min((target.hp - 1).max(0) as u32);
+                target.hp -= amount as i32;
+                if amount > 0 {
+                    self.emit(BattleEvent::Thorns {
+                        source: defender,
+                        target: attacker,
+                        amount,
+                    });
+                }
+            }
+        }
+    }
+
+    fn absorb_with_shield(&mut self, id: CombatantId, damage: u32) -> u32 {
+        let through = status::absorb(&mut self.fighter_mut(id).status, damage);
+        if through < damage {
+            self.emit(BattleEvent::ShieldAbsorbed {
+                target: id,
+                amount: damage - through,
+            });
+        }
+        through
+    }
+
+    /// Heal `id`, capped at max HP. Returns the HP actually restored.
+    fn heal(&mut self, id: CombatantId, amount: u32) -> u32 {
+        let fighter = self.fighter_mut(id);
+        let before = fighter.hp;
+        fighter.hp = (fighter.hp + amount as i32).min(fighter.max_hp);
+        (fighter.hp - before) as u32
+    }
+
+    /// Turn boundary for `id`: tick its status effects, log what happened
+    /// and resolve any knock-out. A stunned fighter passes the turn on.
+    fn tick_statuses(&mut self, id: CombatantId) {
+        let report = status::tick(&mut self.fighter_mut(id).status);
+
+        for &(status, amount) in &report.damage {
+            let fighter = self.fighter_mut(id);
+            fighter.hp = (fighter.hp - amount as i32).max(0);
+            self.emit(BattleEvent::StatusDamage {
+                target: id,
+                status,
+                amount,
+            });
+        }
+        if report.heal > 0 && self.fighter(id).hp > 0 {
+            let amount = self.heal(id, report.heal);
+            self.emit(BattleEvent::Healed {
+                target: id,
+                amount,
+                source: HealSource::Status,
+            });
+        }
+        let knocked_out = self.fighter(id).hp <= 0 && !self.try_revive(id);
+        if !knocked_out && report.stunned {
+            self.emit(BattleEvent::Stunned { id });
+        }
+        for &status in &report.expired {
+            self.emit(BattleEvent::StatusExpired { id, status });
+        }
+
+        if id.side == Turn::Enemy {
+            self.check_phases();
+        }
+        // A fighter knocked out by its own statuses loses the turn too
+        if knocked_out || report.stunned {
+            self.end_turn();
+        }
+    }
+
+    /// Spend one of the side's revives on a fallen fighter. Returns true
+    /// if they got back up.
+    fn try_revive(&mut self, id: CombatantId) -> bool {
+        if self.fighter(id).hp > 0 || !self.side_mut(id.side).inventory.take(ItemKind::Revive) {
+            return false;
+        }
+        let fighter = self.fighter_mut(id);
+        fighter.hp = (fighter.max_hp * items::REVIVE_HP_PERCENT as i32 / 100).max(1);
+        let hp = fighter.hp;
+        self.emit(BattleEvent::Revived { id, hp });
+        true
+    }
+
+    /// Take a fallen fighter off the field and send in the first reserve
+    /// still standing, if any.
+    fn knock_out(&mut self, id: CombatantId) {
+        self.initiative.remove(id);
+        let team = self.side_mut(id.side);
+        let Some(pos) = team.field.iter().position(|&m| m == id.slot) else {
+            return;
+        };
+        let reserve = team.reserves().next();
+        match reserve {
+            Some(member) => team.field[pos] = member,
+            None => {
+                team.field.remove(pos);
+            }
+        }
+        self.emit(BattleEvent::KnockedOut { id });
+        if let Some(member) = reserve {
+            let incoming = CombatantId::new(id.side, member);
+            self.initiative.add(incoming, self.fighter(incoming).spd);
+            self.emit(BattleEvent::SteppedIn { id: incoming });
+        }
+    }
+
+    /// Clear fallen fighters off the field, spending revives first. Ends
+    /// the battle once a whole side is down; returns true if it did.
+    fn settle_knockouts(&mut self) -> bool {
+        for side in [Turn::Enemy, Turn::Player] {
+            for slot in self.side(side).field.clone() {
+                let id = CombatantId::new(side, slot);
+                if self.fighter(id).hp <= 0 && !self.try_revive(id) {
+                    self.knock_out(id);
+                }
+            }
+            if self.side(side).defeated() {
+                self.finish(match side {
+                    Turn::Player => BattleResult::Defeat,
+                    Turn::Enemy => BattleResult::Victory,
+                });
+                return true;
+            }
+        }
+        false
+    }
+
+    fn finish(&mut self, result: BattleResult) {
+        self.emit(BattleEvent::BattleEnded {
+            result: result.clone(),
+        });
+        if result == BattleResult::Victory && !self.is_hot_seat() {
+            self.roll_loot();
+        }
+        self.result = Some(result);
+    }
+
+    /// Victories have a chance to drop an item into the inventory.
+    fn roll_loot(&mut self) {
+        if self.rng.below(100) >= LOOT_CHANCE {
+            return;
+        }
+        let kind = ItemKind::from_drop_roll(self.rng.below(100));
+        if self.sides[0].inventory.add(kind, 1) > 0 {
+            self.loot = Some(kind);
+            self.emit(BattleEvent::LootDropped { item: kind });
+        }
+    }
+
+    /// Enter every boss phase of the lead enemy whose HP threshold has been
+    /// crossed.
+    fn check_phases(&mut self) {
+        while self.sides[1].members[0].hp > 0 {
+            let Some(phase) = self.enemies[0].phases.get(self.enemy_phase).cloned() else {
+                return;
+            };
+            let boss = &mut self.sides[1].members[0];
+            if boss.hp * 100 > boss.max_hp * phase.below_percent as i32 {
+                return;
+            }
+            self.enemy_phase += 1;
+            boss.atk += phase.atk_bonus;
+            boss.def += phase.def_bonus;
+            if let Some(behaviour) = phase.behaviour.filter(|_| boss.ai.is_some()) {
+                boss.ai = Some(behaviour);
+            }
+            self.emit(BattleEvent::PhaseStarted {
+                id: CombatantId::new(Turn::Enemy, 0),
+                phase: self.enemy_phase - 1,
+            });
+            for enemy in phase.summon.iter().filter_map(|n| EnemyTemplate::minion(n)) {
+                self.summon(enemy);
+            }
+        }
+    }
+
+    /// Bring `enemy` into the fight on the enemy side: onto the field if
+    /// there's room, otherwise into reserve.
+    fn summon(&mut self, enemy: EnemyTemplate) {
+        let team = &mut self.sides[1];
+        team.members.push(Combatant::from_enemy(&enemy));
+        let id = CombatantId::new(Turn::Enemy, team.members.len() - 1);
+        if team.field.len() < FIELD_SIZE {
+            team.field.push(id.slot);
+            self.initiative.add(id, self.fighter(id).spd);
+        }
+        self.enemies.push(enemy);
+        self.summoned += 1;
+        self.emit(BattleEvent::Summoned { id });
+    }
+
+    /// After a fighter has acted: settle knock-outs, then hand the turn to
+    /// whoever is next in the initiative queue.
+    fn end_turn(&mut self) {
+        self.check_phases();
+        if self.settle_knockouts() {
+            return;
+        }
+        let next = self
+            .initiative
+            .next()
+            .unwrap_or(CombatantId::new(Turn::Player, 0));
+        self.start_turn(next);
+    }
+
+    /// Turn boundary before `id` acts: drop its guard, count down its
+    /// cooldowns, apply regeneration and tick its statuses.
+    fn start_turn(&mut self, id: CombatantId) {
+        self.turn = id.side;
+        self.actor = id.slot;
+        self.emit(BattleEvent::TurnStarted { id });
+        let fighter = self.fighter_mut(id);
+        fighter.defending = false;
+        for slot in &mut fighter.abilities {
+            slot.cooldown_left = slot.cooldown_left.saturating_sub(1);
+        }
+        for passive in self.fighter(id).passives.clone() {
+            if let Passive::Regeneration { hp } = passive {
+                let amount = self.heal(id, hp);
+                if amount > 0 {
+                    self.emit(BattleEvent::Healed {
+                        target: id,
+                        amount,
+                        source: HealSource::Regeneration,
+                    });
+                }
+            }
+        }
+        self.tick_statuses(id);
+    }
+}
+
+/// Deterministic PRNG (SplitMix64) owned by a battle.
+/// Every roll in a fight goes through this so the whole battle is a pure
+/// function of its seed and the player's actions.
+#[derive(Clone, Debug)]
+pub struct BattleRng {
+    state: u64,
+}
+
+impl BattleRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..max` (returns 0 when `max` is 0).
+    pub fn below(&mut self, max: u32) -> u32 {
+        if max == 0 {
+            return 0;
+        }
+        (self.next_u64() % max as u64) as u32
+    }
 }
 
-fn simple_random(max: u32) -> u32 {
-    let mut buf = [0u8; 4];
+/// Fresh seed from the platform entropy source.
+pub fn random_seed() -> u64 {
+    let mut buf = [0u8; 8];
     getrandom::getrandom(&mut buf).unwrap_or_default();
-    u32::from_le_bytes(buf) % max
+    u64::from_le_bytes(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::affinity::Effectiveness;
+
+    fn fighter() -> CharacterTemplate {
+        CharacterTemplate {
+            name: "Tester".to_string(),
+            hp: 100,
+            atk: 12,
+            def: 6,
+            speed: 12,
+            hue: 0,
+            affinity: Affinity::Neutral,
+            description: String::new(),
+            abilities: vec![],
+            equipment: vec![],
+        }
+    }
+
+    fn dummy() -> EnemyTemplate {
+        EnemyTemplate {
+            name: "Dummy".to_string(),
+            hp: 100,
+            atk: 10,
+            def: 6,
+            speed: 8,
+            hue: 0,
+            affinity: Affinity::Neutral,
+            behaviour: EnemyBehaviour::Aggressive,
+            description: String::new(),
+            on_hit: None,
+            abilities: vec![],
+            phases: vec![],
+        }
+    }
+
+    /// The player's opening attack from `seed`: the first strike event.
+    fn opening_attack(seed: u64) -> BattleEvent {
+        let mut battle = BattleState::team_battle(&[fighter()], &[dummy()], seed);
+        // The faster side opens
+        assert!(battle.awaiting_input());
+        assert!(battle.act(Action::Attack));
+        battle
+            .events
+            .into_iter()
+            .find(|e| e.impact().is_some())
+            .expect("an attack always strikes")
+    }
+
+    fn hit(amount: u32, crit: bool) -> BattleEvent {
+        BattleEvent::DamageDealt {
+            source: CombatantId::new(Turn::Player, 0),
+            target: CombatantId::new(Turn::Enemy, 0),
+            amount,
+            crit,
+            ability: None,
+            effectiveness: Effectiveness::Normal,
+        }
+    }
+
+    #[test]
+    fn rng_is_pinned_to_its_seed() {
+        let mut rng = BattleRng::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+        let rolls = |seed| {
+            let mut rng = BattleRng::new(seed);
+            (0..8).map(|_| rng.below(100)).collect::<Vec<_>>()
+        };
+        assert_eq!(rolls(7), rolls(7));
+        assert_ne!(rolls(7), rolls(8));
+        assert_eq!(BattleRng::new(3).below(0), 0);
+    }
+
+    #[test]
+    fn seeded_hit() {
+        // 12 ATK against 6 DEF: 9 plus 0..5 variance
+        assert_eq!(opening_attack(1), hit(13, false));
+        assert_eq!(opening_attack(2), hit(10, false));
+    }
+
+    #[test]
+    fn seeded_crit() {
+        // (9 + 2) at 150%
+        assert_eq!(opening_attack(8), hit(16, true));
+    }
+
+    #[test]
+    fn seeded_miss() {
+        assert_eq!(
+            opening_attack(40),
+            BattleEvent::Missed {
+                source: CombatantId::new(Turn::Player, 0),
+                target: CombatantId::new(Turn::Enemy, 0),
+                evaded: false,
+                avoided: 9,
+            }
+        );
+    }
+
+    #[test]
+    fn same_seed_same_battle() {
+        let play = |seed| {
+            let mut battle = BattleState::team_battle(&[fighter()], &[dummy()], seed);
+            while battle.result.is_none() {
+                if battle.ai_turn().is_none() {
+                    battle.act(Action::Attack);
+                }
+            }
+            battle.events
+        };
+        assert_eq!(play(11), play(11));
+        assert_ne!(play(11), play(12));
+    }
+
+    #[test]
+    fn recoil_is_logged() {
+        let mut battle = BattleState::team_battle(&[fighter()], &[dummy()], 1);
+        let player = CombatantId::new(Turn::Player, 0);
+        battle.fighter_mut(player).abilities.push(AbilitySlot {
+            ability: Ability {
+                id: "reckless_swing".to_string(),
+                cooldown: 1,
+                target: AbilityTarget::Caster,
+                effects: vec![AbilityEffect::Damage {
+                    percent: 50,
+                    pierce: false,
+                }],
+            },
+            cooldown_left: 0,
+        });
+        assert!(battle.act(Action::Ability(0)));
+        // Half of 12 ATK
+        assert_eq!(battle.fighter(player).hp, 94);
+        let recoil = Locale::En.format("battle.recoil.you", &[("amount", 6u32.into())]);
+        assert!(battle.log(Locale::En).iter().any(|e| e.message == recoil));
+    }
+
+    #[test]
+    fn missed_attacks_build_no_combo() {
+        let mut battle = BattleState::team_battle(&[fighter()], &[dummy()], 40);
+        let player = CombatantId::new(Turn::Player, 0);
+        assert!(battle.act(Action::Attack));
+        assert!(battle.events.iter().any(|e| matches!(e, BattleEvent::Missed { .. })));
+        assert_eq!(battle.fighter(player).combo, Combo::default());
+    }
+
+    #[test]
+    fn combos_are_kept_per_fighter() {
+        let party = [fighter(), fighter()];
+        let mut battle = BattleState::team_battle(&party, &[dummy()], 1);
+        let first = CombatantId::new(Turn::Player, 0);
+        // The first fighter defends, then the second attacks
+        let mut attacked_after_defend = false;
+        while !attacked_after_defend {
+            assert!(battle.result.is_none());
+            if battle.ai_turn().is_some() {
+                continue;
+            }
+            if battle.acting_id() == first {
+                assert!(battle.act(Action::Defend));
+            } else {
+                attacked_after_defend = battle.fighter(first).combo.next_attack().is_some();
+                assert!(battle.act(Action::Attack));
+            }
+        }
+        // The teammate's attack doesn't use up the first fighter's counter
+        assert_eq!(battle.fighter(first).combo.next_attack(), Some(ComboKind::Counter));
+    }
 }
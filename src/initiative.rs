@@ -0,0 +1,126 @@
+use crate::game_state::Turn;
+
+/// Gauge a combatant must fill before it gets to act.
+pub const ACTION_THRESHOLD: u32 = 100;
+
+/// Speed used when a template doesn't specify one.
+pub const DEFAULT_SPEED: u32 = 10;
+
+/// One participant in the turn order: which side it fights for and its
+/// position within that side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CombatantId {
+    pub side: Turn,
+    pub slot: usize,
+}
+
+impl CombatantId {
+    pub fn new(side: Turn, slot: usize) -> Self {
+        Self { side, slot }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    id: CombatantId,
+    speed: u32,
+    gauge: u32,
+}
+
+/// Initiative queue. Every combatant fills a gauge by its speed; whoever
+/// reaches `ACTION_THRESHOLD` first acts and pays the threshold back, so a
+/// combatant twice as fast acts twice as often. Ties go to whoever joined
+/// the queue first. No randomness, so the order is part of the replay.
+#[derive(Clone, Debug, Default)]
+pub struct TurnQueue {
+    entries: Vec<Entry>,
+}
+
+impl TurnQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, id: CombatantId, speed: u32) {
+        self.entries.push(Entry {
+            id,
+            speed: speed.max(1),
+            gauge: 0,
+        });
+    }
+
+    /// Drop a combatant from the order (knocked out or swapped out).
+    pub fn remove(&mut self, id: CombatantId) {
+        self.entries.retain(|e| e.id != id);
+    }
+
+    /// Advance time until someone is ready and hand them the turn.
+    pub fn next(&mut self) -> Option<CombatantId> {
+        let wait = self
+            .entries
+            .iter()
+            .map(|e| ACTION_THRESHOLD.saturating_sub(e.gauge).div_ceil(e.speed))
+            .min()?;
+        for entry in &mut self.entries {
+            entry.gauge += entry.speed * wait;
+        }
+        // Fullest gauge wins; `max_by_key` keeps the last maximum, so search
+        // in reverse to let the earlier entry win ties
+        let entry = self.entries.iter_mut().rev().max_by_key(|e| e.gauge)?;
+        entry.gauge -= ACTION_THRESHOLD;
+        Some(entry.id)
+    }
+
+    /// The next `count` turns, without consuming them.
+    pub fn preview(&self, count: usize) -> Vec<CombatantId> {
+        let mut queue = self.clone();
+        (0..count).map_while(|_| queue.next()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYER: CombatantId = CombatantId {
+        side: Turn::Player,
+        slot: 0,
+    };
+    const ENEMY: CombatantId = CombatantId {
+        side: Turn::Enemy,
+        slot: 0,
+    };
+
+    fn queue(combatants: &[(CombatantId, u32)]) -> TurnQueue {
+        let mut queue = TurnQueue::new();
+        for &(id, speed) in combatants {
+            queue.add(id, speed);
+        }
+        queue
+    }
+
+    #[test]
+    fn faster_combatants_act_more_often() {
+        let turns = queue(&[(PLAYER, 20), (ENEMY, 10)]).preview(30);
+        assert_eq!(turns.iter().filter(|&&id| id == PLAYER).count(), 20);
+        assert_eq!(turns.iter().filter(|&&id| id == ENEMY).count(), 10);
+    }
+
+    #[test]
+    fn ties_go_to_whoever_joined_first() {
+        let turns = queue(&[(PLAYER, 10), (ENEMY, 10)]).preview(4);
+        assert_eq!(turns, vec![PLAYER, ENEMY, PLAYER, ENEMY]);
+        let turns = queue(&[(ENEMY, 10), (PLAYER, 10)]).preview(4);
+        assert_eq!(turns, vec![ENEMY, PLAYER, ENEMY, PLAYER]);
+    }
+
+    #[test]
+    fn acting_costs_exactly_the_threshold() {
+        // 150 against 100: the fast one's spare 50 carries over, so it gets
+        // three turns for every two, and whoever sits exactly on the
+        // threshold acts without waiting another tick
+        let turns = queue(&[(PLAYER, 150), (ENEMY, ACTION_THRESHOLD)]).preview(10);
+        let cycle = [PLAYER, ENEMY, PLAYER, PLAYER, ENEMY];
+        assert_eq!(turns, [cycle, cycle].concat());
+    }
+}
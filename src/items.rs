@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+
+use crate::content;
+use crate::locale::Locale;
+use crate::wallet;
+
+/// Most of a single item a player can carry.
+pub const MAX_STACK: u32 = 9;
+
+/// Percent of max HP restored by a healing potion.
+pub const POTION_HEAL_PERCENT: u32 = 35;
+/// ATK added by an attack tonic for the rest of the battle.
+pub const TONIC_ATK_BONUS: u32 = 4;
+/// Percent of max HP a revive brings the player back with.
+pub const REVIVE_HP_PERCENT: u32 = 30;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ItemKind {
+    HealingPotion,
+    AttackTonic,
+    SmokeBomb,
+    /// Used automatically when the player would fall.
+    Revive,
+}
+
+impl ItemKind {
+    pub fn all() -> [ItemKind; 4] {
+        [
+            ItemKind::HealingPotion,
+            ItemKind::AttackTonic,
+            ItemKind::SmokeBomb,
+            ItemKind::Revive,
+        ]
+    }
+
+    /// This item's entry in the game content.
+    fn def(self) -> &'static ItemDef {
+        content::get()
+            .items
+            .iter()
+            .find(|d| d.kind == self)
+            .expect("content validation requires every item")
+    }
+
+    pub fn label(self, locale: Locale) -> String {
+        locale.t(match self {
+            ItemKind::HealingPotion => "item.potion.name",
+            ItemKind::AttackTonic => "item.tonic.name",
+            ItemKind::SmokeBomb => "item.smoke_bomb.name",
+            ItemKind::Revive => "item.revive.name",
+        })
+    }
+
+    pub fn description(self, locale: Locale) -> String {
+        match self {
+            ItemKind::HealingPotion => locale.format(
+                "item.potion.description",
+                &[("percent", POTION_HEAL_PERCENT.into())],
+            ),
+            ItemKind::AttackTonic => locale.format(
+                "item.tonic.description",
+                &[("amount", TONIC_ATK_BONUS.into())],
+            ),
+            ItemKind::SmokeBomb => locale.t("item.smoke_bomb.description"),
+            ItemKind::Revive => locale.format(
+                "item.revive.description",
+                &[("percent", REVIVE_HP_PERCENT.into())],
+            ),
+        }
+    }
+
+    /// Whether the item can be used as an action (a revive triggers itself).
+    pub fn usable(self) -> bool {
+        self != ItemKind::Revive
+    }
+
+    /// Pick a drop from a roll in `0..100`.
+    pub fn from_drop_roll(roll: u32) -> ItemKind {
+        let mut acc = 0;
+        for kind in ItemKind::all() {
+            acc += kind.def().drop_weight;
+            if roll < acc {
+                return kind;
+            }
+        }
+        ItemKind::HealingPotion
+    }
+}
+
+/// Content entry for an item kind; its name is in the catalogues and what
+/// it does stays in code.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemDef {
+    pub kind: ItemKind,
+    /// Relative chance of this item being a victory drop (all items add up
+    /// to 100).
+    pub drop_weight: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub kind: ItemKind,
+    pub count: u32,
+}
+
+/// Consumables a player owns, carried into every battle.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    pub stacks: Vec<ItemStack>,
+}
+
+impl Inventory {
+    /// What a wallet starts out with.
+    pub fn starter() -> Self {
+        let mut inv = Self::default();
+        inv.add(ItemKind::HealingPotion, 2);
+        inv.add(ItemKind::SmokeBomb, 1);
+        inv
+    }
+
+    pub fn count(&self, kind: ItemKind) -> u32 {
+        self.stacks
+            .iter()
+            .find(|s| s.kind == kind)
+            .map_or(0, |s| s.count)
+    }
+
+    /// Add items, capped at `MAX_STACK`. Returns how many were kept.
+    pub fn add(&mut self, kind: ItemKind, count: u32) -> u32 {
+        let stack = match self.stacks.iter().position(|s| s.kind == kind) {
+            Some(pos) => &mut self.stacks[pos],
+            None => {
+                self.stacks.push(ItemStack { kind, count: 0 });
+                self.stacks.last_mut().unwrap()
+            }
+        };
+        let kept = count.min(MAX_STACK.saturating_sub(stack.count));
+        stack.count += kept;
+        kept
+    }
+
+    /// Every stack capped at `MAX_STACK`, for an inventory read back from
+    /// storage that may have been edited by hand.
+    fn clamped(mut self) -> Self {
+        for stack in &mut self.stacks {
+            stack.count = stack.count.min(MAX_STACK);
+        }
+        self
+    }
+
+    /// Remove one item. Returns false if there was none.
+    pub fn take(&mut self, kind: ItemKind) -> bool {
+        match self
+            .stacks
+            .iter_mut()
+            .find(|s| s.kind == kind && s.count > 0)
+        {
+            Some(stack) => {
+                stack.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn storage_key(pubkey: &str) -> String {
+    format!("mojo_inventory_{}", pubkey)
+}
+
+/// Inventory saved for a wallet, or the starter kit for a new one.
+pub fn load(pubkey: &str) -> Inventory {
+    wallet::load_from_storage(&storage_key(pubkey))
+        .and_then(|s| serde_json::from_str::<Inventory>(&s).ok())
+        .map(Inventory::clamped)
+        .unwrap_or_else(Inventory::starter)
+}
+
+pub fn save(pubkey: &str, inventory: &Inventory) {
+    if let Ok(json) = serde_json::to_string(inventory) {
+        wallet::save_to_storage(&storage_key(pubkey), &json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adding_to_an_overfull_stack_keeps_nothing() {
+        let mut inv = Inventory {
+            stacks: vec![ItemStack {
+                kind: ItemKind::HealingPotion,
+                count: MAX_STACK + 5,
+            }],
+        };
+        assert_eq!(inv.add(ItemKind::HealingPotion, 3), 0);
+        assert_eq!(inv.clamped().count(ItemKind::HealingPotion), MAX_STACK);
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+/// Message catalogues, compiled in. English is the reference: every other
+/// language must have the same keys and may only use English's
+/// placeholders.
+const CATALOGUES: [(Locale, &str); 2] = [
+    (Locale::En, include_str!("../data/locales/en.json")),
+    (Locale::Es, include_str!("../data/locales/es.json")),
+];
+
+/// A language the UI can be shown in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+/// One catalogue entry: plain text, or a form per plural category picked
+/// by the `count` argument.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Message {
+    Text(String),
+    Plural(BTreeMap<String, String>),
+}
+
+type Catalogue = BTreeMap<String, Message>;
+
+/// Plural categories a catalogue may use. Both languages only tell one
+/// from many.
+const PLURAL_CATEGORIES: [&str; 2] = ["one", "other"];
+
+/// A value substituted into a `{placeholder}`. Numbers are grouped the
+/// way the locale writes them.
+#[derive(Clone, Debug)]
+pub enum Arg {
+    Text(String),
+    Number(i64),
+}
+
+impl From<&str> for Arg {
+    fn from(s: &str) -> Self {
+        Arg::Text(s.to_string())
+    }
+}
+
+impl From<String> for Arg {
+    fn from(s: String) -> Self {
+        Arg::Text(s)
+    }
+}
+
+impl From<u32> for Arg {
+    fn from(n: u32) -> Self {
+        Arg::Number(n as i64)
+    }
+}
+
+impl From<i32> for Arg {
+    fn from(n: i32) -> Self {
+        Arg::Number(n as i64)
+    }
+}
+
+impl From<usize> for Arg {
+    fn from(n: usize) -> Self {
+        Arg::Number(n as i64)
+    }
+}
+
+static PARSED: OnceLock<BTreeMap<Locale, Catalogue>> = OnceLock::new();
+
+/// Every catalogue, parsed and checked against English on first use. A bad
+/// catalogue is a mistake in the build, so this panics listing every
+/// problem.
+fn catalogues() -> &'static BTreeMap<Locale, Catalogue> {
+    PARSED.get_or_init(|| {
+        let mut parsed = BTreeMap::new();
+        let mut errors = Vec::new();
+        for (locale, json) in CATALOGUES {
+            match serde_json::from_str::<Catalogue>(json) {
+                Ok(catalogue) => {
+                    parsed.insert(locale, catalogue);
+                }
+                Err(e) => errors.push(format!("{}: {}", locale.code(), e)),
+            }
+        }
+        if let Some(en) = parsed.get(&Locale::En) {
+            for (locale, catalogue) in &parsed {
+                errors.extend(
+                    problems(en, catalogue)
+                        .into_iter()
+                        .map(|p| format!("{}: {}", locale.code(), p)),
+                );
+            }
+        }
+        if !errors.is_empty() {
+            panic!("Invalid data/locales:\n{}", errors.join("\n"));
+        }
+        parsed
+    })
+}
+
+/// Parse and check every catalogue up front.
+pub fn check() {
+    catalogues();
+}
+
+/// What's wrong with `catalogue` compared to the English one.
+fn problems(en: &Catalogue, catalogue: &Catalogue) -> Vec<String> {
+    let mut problems = Vec::new();
+    for key in en.keys().filter(|k| !catalogue.contains_key(*k)) {
+        problems.push(format!("missing \"{}\"", key));
+    }
+    for (key, message) in catalogue {
+        let Some(reference) = en.get(key) else {
+            problems.push(format!("unknown key \"{}\"", key));
+            continue;
+        };
+        let forms: Vec<&String> = match (reference, message) {
+            (Message::Text(_), Message::Text(text)) => vec![text],
+            (Message::Plural(_), Message::Plural(forms)) => {
+                if !forms.contains_key("other") {
+                    problems.push(format!("\"{}\" has no \"other\" form", key));
+                }
+                for category in forms.keys() {
+                    if !PLURAL_CATEGORIES.contains(&category.as_str()) {
+                        problems.push(format!(
+                            "\"{}\" has unknown plural category \"{}\"",
+                            key, category
+                        ));
+                    }
+                }
+                forms.values().collect()
+            }
+            _ => {
+                problems.push(format!(
+                    "\"{}\" should be plural only where English is",
+                    key
+                ));
+                continue;
+            }
+        };
+        let allowed = placeholders_of(reference);
+        for form in forms {
+            for name in placeholders(form) {
+                if !allowed.contains(name) {
+                    problems.push(format!("\"{}\" uses unknown placeholder {{{}}}", key, name));
+                }
+            }
+        }
+    }
+    problems
+}
+
+/// Names of the `{placeholders}` in `text`.
+fn placeholders(text: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        names.push(&rest[start + 1..start + len]);
+        rest = &rest[start + len + 1..];
+    }
+    names
+}
+
+fn placeholders_of(message: &Message) -> BTreeSet<&str> {
+    match message {
+        Message::Text(text) => placeholders(text).into_iter().collect(),
+        Message::Plural(forms) => forms.values().flat_map(|f| placeholders(f)).collect(),
+    }
+}
+
+/// The message at `key` in `locale`'s catalogue, or else in English's.
+fn lookup<'a>(
+    catalogues: &'a BTreeMap<Locale, Catalogue>,
+    locale: Locale,
+    key: &str,
+) -> Option<&'a Message> {
+    [locale, Locale::En]
+        .iter()
+        .find_map(|l| catalogues.get(l).and_then(|c| c.get(key)))
+}
+
+impl Locale {
+    pub fn all() -> [Locale; 2] {
+        [Locale::En, Locale::Es]
+    }
+
+    /// BCP 47 language code, as stored and set on `<html lang>`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// The locale for a language code; regional variants ("es-MX") match
+    /// their language.
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let language = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        Locale::all().into_iter().find(|l| l.code() == language)
+    }
+
+    /// The language's name in itself, for the switcher.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+        }
+    }
+
+    /// Whether the catalogues have a message at `key`. Every catalogue
+    /// has English's keys, so this is the same for every locale.
+    pub fn has(key: &str) -> bool {
+        catalogues()
+            .get(&Locale::En)
+            .is_some_and(|c| c.contains_key(key))
+    }
+
+    /// The message at `key`, falling back to English and then to the key
+    /// itself.
+    pub fn t(self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    /// The message at `key` with its `{placeholders}` filled from `args`.
+    /// Plural messages pick their form by the `count` argument.
+    pub fn format(self, key: &str, args: &[(&str, Arg)]) -> String {
+        let template = match lookup(catalogues(), self, key) {
+            None => return key.to_string(),
+            Some(Message::Text(text)) => text,
+            Some(Message::Plural(forms)) => {
+                let count = args.iter().find_map(|(name, arg)| match arg {
+                    Arg::Number(n) if *name == "count" => Some(*n),
+                    _ => None,
+                });
+                let category = if count == Some(1) { "one" } else { "other" };
+                match forms.get(category).or_else(|| forms.get("other")) {
+                    Some(form) => form,
+                    None => return key.to_string(),
+                }
+            }
+        };
+
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let Some(len) = rest[start..].find('}') else {
+                rest = &rest[start..];
+                break;
+            };
+            let name = &rest[start + 1..start + len];
+            match args.iter().find(|(n, _)| *n == name) {
+                Some((_, Arg::Text(text))) => out.push_str(text),
+                Some((_, Arg::Number(n))) => out.push_str(&self.number(*n)),
+                None => out.push_str(&rest[start..=start + len]),
+            }
+            rest = &rest[start + len + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// `n` with its thousands grouped: 12,345 in English, 12.345 in
+    /// Spanish (which leaves four-digit numbers alone).
+    pub fn number(self, n: i64) -> String {
+        let digits = n.unsigned_abs().to_string();
+        let (separator, min_digits) = match self {
+            Locale::En => (',', 4),
+            Locale::Es => ('.', 5),
+        };
+        let mut out = String::new();
+        if n < 0 {
+            out.push('-');
+        }
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && digits.len() >= min_digits && (digits.len() - i).is_multiple_of(3) {
+                out.push(separator);
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    /// A two-place decimal given in hundredths: 1.25 or 1,25.
+    pub fn decimal(self, hundredths: i64) -> String {
+        let separator = match self {
+            Locale::En => '.',
+            Locale::Es => ',',
+        };
+        let sign = if hundredths < 0 { "-" } else { "" };
+        let abs = hundredths.unsigned_abs();
+        format!(
+            "{}{}{}{:02}",
+            sign,
+            self.number((abs / 100) as i64),
+            separator,
+            abs % 100
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plurals_follow_the_count() {
+        let cases = [
+            (Locale::En, 0, "0 POINTS LEFT"),
+            (Locale::En, 1, "1 POINT LEFT"),
+            (Locale::En, 7, "7 POINTS LEFT"),
+            (Locale::Es, 0, "QUEDAN 0 PUNTOS"),
+            (Locale::Es, 1, "QUEDA 1 PUNTO"),
+            (Locale::Es, 7, "QUEDAN 7 PUNTOS"),
+        ];
+        for (locale, count, expected) in cases {
+            let text = locale.format("creator.points_left", &[("count", count.into())]);
+            assert_eq!(text, expected);
+        }
+    }
+
+    #[test]
+    fn numbers_are_grouped_per_locale() {
+        let cases = [
+            (Locale::En, 999, "999"),
+            (Locale::En, 1234, "1,234"),
+            (Locale::En, 1234567, "1,234,567"),
+            (Locale::En, -12345, "-12,345"),
+            (Locale::Es, 1234, "1234"),
+            (Locale::Es, 12345, "12.345"),
+            (Locale::Es, 1234567, "1.234.567"),
+        ];
+        for (locale, n, expected) in cases {
+            assert_eq!(locale.number(n), expected);
+        }
+        assert_eq!(Locale::En.decimal(123456), "1,234.56");
+        assert_eq!(Locale::Es.decimal(125), "1,25");
+    }
+
+    #[test]
+    fn missing_keys_fall_back_to_english() {
+        let catalogue = |json: &str| serde_json::from_str::<Catalogue>(json).unwrap();
+        let catalogues = BTreeMap::from([
+            (Locale::En, catalogue(r#"{"greeting": "Hello", "farewell": "Bye"}"#)),
+            (Locale::Es, catalogue(r#"{"greeting": "Hola"}"#)),
+        ]);
+        let text = |key| match lookup(&catalogues, Locale::Es, key) {
+            Some(Message::Text(text)) => Some(text.as_str()),
+            _ => None,
+        };
+        assert_eq!(text("greeting"), Some("Hola"));
+        assert_eq!(text("farewell"), Some("Bye"));
+        assert_eq!(text("nowhere"), None);
+        // ...and past English, to the key itself
+        assert_eq!(Locale::Es.t("no.such.key"), "no.such.key");
+    }
+}
@@ -1,12 +1,34 @@
+mod abilities;
+mod affinity;
 mod app;
+#[cfg(test)]
+mod balance;
+mod battle_event;
+mod campaign;
+mod combo;
 mod components;
+mod content;
+mod creator;
+mod enemy_ai;
+mod equipment;
 mod game_state;
+mod initiative;
+mod items;
+mod locale;
 mod pages;
+mod progression;
+mod replay;
+mod rival;
+mod score;
 mod solana_bridge;
+mod status;
 pub mod svg_metadata;
 mod wallet;
 
 fn main() {
     console_error_panic_hook::set_once();
+    // Surface content errors before anything renders
+    content::get();
+    locale::check();
     leptos::mount::mount_to_body(app::App);
 }
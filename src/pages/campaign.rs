@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+
+use crate::app::{CampaignState, CustomCharacters, HotSeatState, RivalState, SelectedCharacter};
+use crate::campaign::{self, CampaignRun};
+use crate::components::enemy_card::EnemyCard;
+use crate::locale::Locale;
+
+#[component]
+pub fn CampaignPage() -> impl IntoView {
+    let selected_char = expect_context::<RwSignal<SelectedCharacter>>();
+    let campaign_state = expect_context::<RwSignal<CampaignState>>();
+    let hot_seat = expect_context::<RwSignal<HotSeatState>>();
+    let rival_state = expect_context::<RwSignal<RivalState>>();
+    let custom = expect_context::<RwSignal<CustomCharacters>>();
+    let locale = expect_context::<RwSignal<Locale>>();
+    // A campaign fight is never a PvP match
+    let leave_pvp = move || {
+        hot_seat.set(HotSeatState::default());
+        rival_state.set(RivalState::default());
+    };
+
+    let hero = move || {
+        selected_char
+            .get()
+            .index
+            .and_then(|i| custom.get().template(i).map(|c| c.name))
+    };
+    let in_progress = move || {
+        campaign_state
+            .get()
+            .run
+            .filter(|run| !run.over)
+            .map(|run| (run.stage + 1, run.stage_count()))
+    };
+
+    let encounters = campaign::encounters()
+        .into_iter()
+        .enumerate()
+        .map(|(i, enemy)| {
+            view! {
+                <div style="display: flex; flex-direction: column; align-items: center; gap: 0.3rem;">
+                    <span class="metadata-label">
+                        {move || locale.get().format("campaign.encounter", &[("number", (i + 1).into())])}
+                    </span>
+                    <EnemyCard enemy=enemy selected=false on_click=|_| {} />
+                </div>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="page page-enter">
+            <h2 class="section-title">{move || locale.get().t("campaign.title")}</h2>
+            <p style="color: var(--text-dim); font-size: 0.8rem; max-width: 600px; text-align: center;">
+                {move || locale.get().format(
+                    "campaign.intro",
+                    &[("percent", campaign::REST_HEAL_PERCENT.into())],
+                )}
+            </p>
+            <div class="character-grid">{encounters}</div>
+            <div style="display: flex; gap: 1rem; align-items: center;">
+                {move || in_progress().map(|(stage, total)| view! {
+                    <a href="/battle">
+                        <button class="btn" on:click=move |_| leave_pvp()>
+                            {move || locale.get().format(
+                                "campaign.continue",
+                                &[("stage", stage.into()), ("total", total.into())],
+                            )}
+                        </button>
+                    </a>
+                })}
+                <a href="/battle">
+                    <button
+                        class="btn"
+                        disabled=move || hero().is_none()
+                        on:click=move |_| {
+                            campaign_state.set(CampaignState { run: Some(CampaignRun::new()) });
+                            leave_pvp();
+                        }
+                    >
+                        {move || match hero() {
+                            Some(name) => locale.get().format(
+                                "campaign.start_as",
+                                &[("name", name.to_uppercase().into())],
+                            ),
+                            None => locale.get().t("campaign.select_first"),
+                        }}
+                    </button>
+                </a>
+            </div>
+        </div>
+    }
+}
@@ -2,9 +2,15 @@ use leptos::prelude::*;
 use leptos_router::hooks::use_navigate;
 use wasm_bindgen_futures::spawn_local;
 
-use crate::app::{CollectionState, MintedCharacterInfo, MintedCharacters, SelectedCharacter, WalletState};
+use crate::app::{
+    CampaignState, CollectionState, CustomCharacters, HotSeatState, MintedCharacterInfo,
+    MintedCharacters, RivalState, SelectedCharacter, SelectedEnemy, WalletState,
+};
 use crate::components::character_card::CharacterCard;
-use crate::game_state::CharacterTemplate;
+use crate::components::enemy_card::EnemyCard;
+use crate::equipment::Loadout;
+use crate::game_state::{EnemyTemplate, MAX_PARTY};
+use crate::locale::Locale;
 use crate::solana_bridge;
 use crate::svg_metadata;
 use crate::wallet;
@@ -13,9 +19,15 @@ use crate::wallet;
 pub fn CharacterSelectPage() -> impl IntoView {
     let wallet_state = expect_context::<RwSignal<WalletState>>();
     let selected_char = expect_context::<RwSignal<SelectedCharacter>>();
+    let selected_enemy = expect_context::<RwSignal<SelectedEnemy>>();
     let collection_state = expect_context::<RwSignal<CollectionState>>();
     let minted_chars = expect_context::<RwSignal<MintedCharacters>>();
-    let characters = CharacterTemplate::all();
+    let campaign_state = expect_context::<RwSignal<CampaignState>>();
+    let hot_seat = expect_context::<RwSignal<HotSeatState>>();
+    let rival_state = expect_context::<RwSignal<RivalState>>();
+    let custom = expect_context::<RwSignal<CustomCharacters>>();
+    let locale = expect_context::<RwSignal<Locale>>();
+    let characters = custom.get_untracked().roster();
     let tx_status = RwSignal::new(Option::<Result<String, String>>::None);
     let minting = RwSignal::new(false);
     let navigate = use_navigate();
@@ -24,18 +36,24 @@ pub fn CharacterSelectPage() -> impl IntoView {
         let sel = selected_char.get_untracked();
         let ws = wallet_state.get_untracked();
         let nav = navigate.clone();
+        let l = locale.get_untracked();
 
         let Some(idx) = sel.index else {
-            tx_status.set(Some(Err("Select a character first".into())));
+            tx_status.set(Some(Err(l.t("tx.select_character_first"))));
             return;
         };
 
         if !ws.connected {
-            tx_status.set(Some(Err("Connect wallet first".into())));
+            tx_status.set(Some(Err(l.t("tx.connect_wallet_first"))));
             return;
         }
 
-        let character = CharacterTemplate::all()[idx].clone();
+        // Custom heroes that fail validation have no template, so an
+        // illegal stat spread never reaches the mint
+        let Some(character) = custom.get_untracked().template(idx) else {
+            tx_status.set(Some(Err(l.t("tx.cannot_mint"))));
+            return;
+        };
         let pubkey_str = ws.pubkey.unwrap_or_default();
 
         minting.set(true);
@@ -45,14 +63,14 @@ pub fn CharacterSelectPage() -> impl IntoView {
             let result = async {
                 let pubkey_bytes: [u8; 32] = bs58_decode(&pubkey_str)?
                     .try_into()
-                    .map_err(|_| "Invalid pubkey length".to_string())?;
+                    .map_err(|_| l.t("tx.invalid_pubkey"))?;
                 let pubkey = solana_pubkey::Pubkey::new_from_array(pubkey_bytes);
 
                 // Step 1: Create collection if we don't have one yet
                 let collection_pubkey = if let Some(col) = collection_state.get_untracked().pubkey {
                     col
                 } else {
-                    tx_status.set(Some(Ok("Creating collection... (approve in Phantom)".into())));
+                    tx_status.set(Some(Ok(l.t("tx.creating_collection"))));
 
                     let col_meta = r#"{"name":"Mojo Arena Characters","description":"On-chain characters for the Mojo Arena demo","image":""}"#;
                     let collection_uri = wallet::upload_to_irys(col_meta, "application/json").await
@@ -62,7 +80,7 @@ pub fn CharacterSelectPage() -> impl IntoView {
                         "Mojo Arena Characters",
                         &collection_uri,
                     )
-                    .map_err(|e| format!("Build collection tx: {}", e))?;
+                    .map_err(|e| l.format("tx.build_collection_failed", &[("error", e.to_string().into())]))?;
 
                     // Extract the collection pubkey from the ephemeral signer
                     use solana_signer::Signer;
@@ -75,7 +93,7 @@ pub fn CharacterSelectPage() -> impl IntoView {
                         pubkey: Some(col_pubkey),
                     });
 
-                    tx_status.set(Some(Ok("Collection created! Now minting character...".into())));
+                    tx_status.set(Some(Ok(l.t("tx.collection_created"))));
                     col_pubkey
                 };
 
@@ -83,17 +101,17 @@ pub fn CharacterSelectPage() -> impl IntoView {
                 // This mirrors the TS pattern: upload image → get URL → embed in JSON → upload JSON.
                 // First upload prompts ONE Phantom approval for Irys devnet funding.
                 // If upload fails we abort — the metadata_uri must always be a real HTTPS URL.
-                tx_status.set(Some(Ok("Uploading image & metadata to Arweave...".into())));
+                tx_status.set(Some(Ok(l.t("tx.uploading_character"))));
                 let char_uri = wallet::upload_character_metadata(
-                    idx,
+                    character.hue,
                     &character.name,
-                    &character.description,
+                    &character.description(l),
                     character.hp,
                     character.atk,
                     character.def,
                 ).await
-                .map_err(|e| format!("Arweave upload failed: {}", e))?;
-                tx_status.set(Some(Ok("Image on Arweave! Minting character... (approve in Phantom)".into())));
+                .map_err(|e| l.format("tx.upload_failed", &[("error", e.into())]))?;
+                tx_status.set(Some(Ok(l.t("tx.uploaded_minting_character"))));
                 let bundle = mojo_rust_sdk::world::World::build_select_character_tx(
                     &collection_pubkey,
                     pubkey, // authority
@@ -102,7 +120,7 @@ pub fn CharacterSelectPage() -> impl IntoView {
                     &character.name,
                     &char_uri,
                 )
-                .map_err(|e| format!("Build mint tx: {}", e))?;
+                .map_err(|e| l.format("tx.build_mint_failed", &[("error", e.to_string().into())]))?;
 
                 let sig = solana_bridge::send_transaction_bundle(bundle).await?;
                 Ok::<String, String>(sig)
@@ -117,13 +135,16 @@ pub fn CharacterSelectPage() -> impl IntoView {
                             name: character.name.clone(),
                             index: idx,
                             tx_signature: sig.clone(),
+                            xp: 0,
+                            loadout: Loadout::default(),
                         });
                     });
-                    tx_status.set(Some(Ok(format!(
-                        "Minted! Tx: {}...{}",
+                    let tx = format!(
+                        "{}...{}",
                         &sig[..sig.len().min(8)],
                         &sig[sig.len().saturating_sub(8)..]
-                    ))));
+                    );
+                    tx_status.set(Some(Ok(l.format("tx.minted", &[("tx", tx.into())]))));
                     nav("/preview", Default::default());
                 }
                 Err(e) => {
@@ -136,23 +157,101 @@ pub fn CharacterSelectPage() -> impl IntoView {
 
     view! {
         <div class="page page-enter">
-            <h2 class="section-title">"SELECT YOUR CHARACTER"</h2>
+            <h2 class="section-title">{move || locale.get().t("select.title")}</h2>
             <div class="character-grid">
                 {characters
                     .into_iter()
-                    .enumerate()
                     .map(|(i, ch)| {
                         let is_selected = Signal::derive(move || {
                             selected_char.get().index == Some(i)
                         });
+                        let level = Signal::derive(move || minted_chars.get().level_of(i));
+                        let loadout = Signal::derive(move || minted_chars.get().loadout_of(i));
+                        let in_party = move || selected_char.get().party.contains(&i);
+                        // Only minted characters can join, and never the lead twice
+                        let can_join = move || {
+                            let sel = selected_char.get();
+                            minted_chars.get().characters.iter().any(|c| c.index == i)
+                                && sel.index != Some(i)
+                                && (in_party() || sel.lineup().len() < MAX_PARTY)
+                        };
+                        view! {
+                            <div style="display: flex; flex-direction: column; align-items: center; gap: 0.3rem;">
+                                <CharacterCard
+                                    character=ch
+                                    selected=is_selected
+                                    level=level
+                                    loadout=loadout
+                                    on_click=move |_| selected_char.update(|s| s.select(i))
+                                />
+                                <button
+                                    class="btn btn-small"
+                                    disabled=move || !can_join()
+                                    on:click=move |_| selected_char.update(|s| s.toggle_teammate(i))
+                                >
+                                    {move || locale.get().t(if in_party() { "select.party_remove" } else { "select.party_add" })}
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect::<Vec<_>>()}
+            </div>
+            <a href="/create">
+                <button class="btn btn-small">{move || locale.get().t("select.create_hero")}</button>
+            </a>
+            <p style="color: var(--text-dim); font-size: 0.8rem; max-width: 600px; text-align: center;">
+                {move || {
+                    let names = selected_char
+                        .get()
+                        .lineup()
+                        .into_iter()
+                        .filter_map(|i| custom.get().template(i).map(|c| c.name))
+                        .collect::<Vec<_>>();
+                    if names.len() > 1 {
+                        locale.get().format(
+                            "select.party_summary",
+                            &[("names", names.join(", ").into()), ("count", names.len().into())],
+                        )
+                    } else {
+                        locale.get().format("select.party_hint", &[("count", (MAX_PARTY - 1).into())])
+                    }
+                }}
+            </p>
+            {move || selected_char.get().index.and_then(|i| custom.get().template(i)).map(|c| {
+                let l = locale.get();
+                // Opponents by matchup, counting both directions of attack
+                let pick = |favourable: bool| {
+                    EnemyTemplate::all()
+                        .into_iter()
+                        .filter(|e| {
+                            let edge = c.affinity.advantage(e.affinity);
+                            if favourable { edge > 0 } else { edge < 0 }
+                        })
+                        .map(|e| e.name(l))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let or_none = |names: String| if names.is_empty() { "—".to_string() } else { names };
+                view! {
+                    <div class="metadata-field" style="max-width: 600px;">
+                        <span class="metadata-label">{format!("{} · {}", c.name.to_uppercase(), c.affinity.label(l).to_uppercase())}</span>
+                        <span class="metadata-value">{l.format("select.strong_vs", &[("names", or_none(pick(true)).into())])}</span>
+                        <span class="metadata-value">{l.format("select.weak_vs", &[("names", or_none(pick(false)).into())])}</span>
+                    </div>
+                }
+            })}
+            <h2 class="section-title">{move || locale.get().t("select.choose_opponent")}</h2>
+            <div class="character-grid">
+                {EnemyTemplate::all()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, enemy)| {
+                        let is_selected = Signal::derive(move || selected_enemy.get().index == i);
                         view! {
-                            <CharacterCard
-                                character=ch
-                                index=i
+                            <EnemyCard
+                                enemy=enemy
                                 selected=is_selected
-                                on_click=move |_| {
-                                    selected_char.set(SelectedCharacter { index: Some(i) });
-                                }
+                                on_click=move |_| selected_enemy.set(SelectedEnemy { index: i })
                             />
                         }
                     })
@@ -164,14 +263,20 @@ pub fn CharacterSelectPage() -> impl IntoView {
                     on:click=on_mint
                     disabled=move || minting.get() || selected_char.get().index.is_none()
                 >
-                    {move || if minting.get() { "MINTING..." } else { "MINT CHARACTER" }}
+                    {move || locale.get().t(if minting.get() { "select.minting" } else { "select.mint" })}
                 </button>
                 <a href="/battle">
                     <button
                         class="btn"
                         disabled=move || selected_char.get().index.is_none()
+                        // A single fight leaves any campaign run or PvP match behind
+                        on:click=move |_| {
+                            campaign_state.set(CampaignState::default());
+                            hot_seat.set(HotSeatState::default());
+                            rival_state.set(RivalState::default());
+                        }
                     >
-                        "BATTLE →"
+                        {move || locale.get().t("select.battle")}
                     </button>
                 </a>
             </div>
@@ -179,7 +284,7 @@ pub fn CharacterSelectPage() -> impl IntoView {
                 let col = collection_state.get();
                 col.pubkey.map(|_| view! {
                     <div class="tx-status success" style="font-size: 0.7rem;">
-                        "Collection ready — mints go directly"
+                        {locale.get().t("select.collection_ready")}
                     </div>
                 })
             }}
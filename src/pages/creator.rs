@@ -0,0 +1,183 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+
+use crate::app::{CustomCharacters, SelectedCharacter};
+use crate::components::stick_figure::StickFigure;
+use crate::creator::{self, HeroDraft};
+use crate::locale::Locale;
+
+/// Which stat a row of the point-buy edits.
+#[derive(Clone, Copy)]
+enum Stat {
+    Hp,
+    Atk,
+    Def,
+}
+
+impl Stat {
+    fn label(self, locale: Locale) -> String {
+        locale.t(match self {
+            Stat::Hp => "stat.hp",
+            Stat::Atk => "stat.atk",
+            Stat::Def => "stat.def",
+        })
+    }
+
+    fn points(self, draft: &HeroDraft) -> u32 {
+        match self {
+            Stat::Hp => draft.hp_points,
+            Stat::Atk => draft.atk_points,
+            Stat::Def => draft.def_points,
+        }
+    }
+
+    fn points_mut(self, draft: &mut HeroDraft) -> &mut u32 {
+        match self {
+            Stat::Hp => &mut draft.hp_points,
+            Stat::Atk => &mut draft.atk_points,
+            Stat::Def => &mut draft.def_points,
+        }
+    }
+
+    /// The stat's value with the draft's points spent.
+    fn value(self, draft: &HeroDraft) -> u32 {
+        let points = self.points(draft);
+        match self {
+            Stat::Hp => creator::BASE_HP + points * creator::HP_PER_POINT,
+            Stat::Atk => creator::BASE_ATK + points,
+            Stat::Def => creator::BASE_DEF + points,
+        }
+    }
+}
+
+#[component]
+pub fn CreatorPage() -> impl IntoView {
+    let custom = expect_context::<RwSignal<CustomCharacters>>();
+    let selected_char = expect_context::<RwSignal<SelectedCharacter>>();
+    let locale = expect_context::<RwSignal<Locale>>();
+    let navigate = use_navigate();
+
+    let draft = RwSignal::new(HeroDraft {
+        hue: 180,
+        ..HeroDraft::default()
+    });
+    let error = RwSignal::new(Option::<String>::None);
+
+    // The buttons only allow legal spreads; `build` checks again on create
+    let stat_row = move |stat: Stat| {
+        view! {
+            <div class="metadata-field creator-stat">
+                <span class="metadata-label">
+                    {move || {
+                        let l = locale.get();
+                        let points = l.format("points", &[("count", stat.points(&draft.get()).into())]);
+                        l.format(
+                            "creator.stat_points",
+                            &[("stat", stat.label(l).into()), ("points", points.into())],
+                        )
+                    }}
+                </span>
+                <div class="creator-stepper">
+                    <button
+                        class="btn btn-small"
+                        disabled=move || stat.points(&draft.get()) == 0
+                        on:click=move |_| draft.update(|d| *stat.points_mut(d) -= 1)
+                    >
+                        "−"
+                    </button>
+                    <span class="metadata-value">{move || stat.value(&draft.get())}</span>
+                    <button
+                        class="btn btn-small"
+                        disabled=move || {
+                            let d = draft.get();
+                            d.points_left() == 0 || stat.points(&d) >= creator::MAX_POINTS_PER_STAT
+                        }
+                        on:click=move |_| draft.update(|d| *stat.points_mut(d) += 1)
+                    >
+                        "+"
+                    </button>
+                </div>
+            </div>
+        }
+    };
+
+    let on_create = move |_| {
+        let locale = locale.get_untracked();
+        let hero = match draft.get_untracked().build(locale) {
+            Ok(hero) => hero,
+            Err(e) => {
+                error.set(Some(e));
+                return;
+            }
+        };
+        let mut added = Err(String::new());
+        custom.update(|c| added = c.add(hero, locale));
+        match added {
+            Ok(index) => {
+                selected_char.update(|s| s.select(index));
+                navigate("/select", Default::default());
+            }
+            Err(e) => error.set(Some(e)),
+        }
+    };
+
+    view! {
+        <div class="page page-enter">
+            <h2 class="section-title">{move || locale.get().t("creator.title")}</h2>
+            <p style="color: var(--text-dim); font-size: 0.8rem; max-width: 600px; text-align: center;">
+                {move || locale.get().format(
+                    "creator.intro",
+                    &[
+                        ("budget", creator::POINT_BUDGET.into()),
+                        ("max", creator::MAX_POINTS_PER_STAT.into()),
+                    ],
+                )}
+            </p>
+            <div class="preview-layout">
+                <div class="preview-image">
+                    <StickFigure hue=Signal::derive(move || draft.get().hue) />
+                </div>
+                <div class="panel panel-glow" style="display: flex; flex-direction: column; gap: 0.8rem; min-width: 280px;">
+                    <input
+                        class="text-input"
+                        type="text"
+                        placeholder=move || locale.get().t("creator.name")
+                        maxlength=creator::MAX_NAME_LEN
+                        prop:value=move || draft.get().name
+                        on:input=move |ev| draft.update(|d| d.name = event_target_value(&ev))
+                    />
+                    <input
+                        class="text-input"
+                        type="text"
+                        placeholder=move || locale.get().t("creator.description")
+                        maxlength=creator::MAX_DESCRIPTION_LEN
+                        prop:value=move || draft.get().description
+                        on:input=move |ev| draft.update(|d| d.description = event_target_value(&ev))
+                    />
+                    <div class="metadata-field">
+                        <span class="metadata-label">{move || locale.get().t("creator.colour")}</span>
+                        <input
+                            type="range"
+                            min="0"
+                            max="359"
+                            prop:value=move || draft.get().hue.to_string()
+                            on:input=move |ev| {
+                                if let Ok(hue) = event_target_value(&ev).parse() {
+                                    draft.update(|d| d.hue = hue);
+                                }
+                            }
+                        />
+                    </div>
+                    {stat_row(Stat::Hp)}
+                    {stat_row(Stat::Atk)}
+                    {stat_row(Stat::Def)}
+                    <div class="score-display">
+                        {move || locale.get().format("creator.points_left", &[("count", draft.get().points_left().into())])}
+                    </div>
+                    <button class="btn" on:click=on_create>{move || locale.get().t("creator.create")}</button>
+                    {move || error.get().map(|msg| view! { <div class="tx-status error">{msg}</div> })}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -2,8 +2,11 @@ use leptos::prelude::*;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
-use crate::app::{MintedCharacters, WalletState};
-use crate::game_state::CharacterTemplate;
+use crate::app::{CustomCharacters, MintedCharacters, WalletState};
+use crate::equipment::{Equipment, Slot};
+use crate::items::{Inventory, ItemKind};
+use crate::locale::Locale;
+use crate::progression;
 use crate::solana_bridge;
 use crate::svg_metadata;
 use crate::wallet;
@@ -12,6 +15,8 @@ use crate::wallet;
 pub fn EditProfilePage() -> impl IntoView {
     let wallet_state = expect_context::<RwSignal<WalletState>>();
     let minted_chars = expect_context::<RwSignal<MintedCharacters>>();
+    let inventory = expect_context::<RwSignal<Inventory>>();
+    let locale = expect_context::<RwSignal<Locale>>();
     let tx_status = RwSignal::new(Option::<Result<String, String>>::None);
     let saving = RwSignal::new(false);
     let avatar_url = RwSignal::new(Option::<String>::None);
@@ -56,9 +61,10 @@ pub fn EditProfilePage() -> impl IntoView {
     };
 
     let on_save_profile = move |_| {
+        let l = locale.get_untracked();
         let ws = wallet_state.get_untracked();
         if !ws.connected {
-            tx_status.set(Some(Err("Connect wallet first".into())));
+            tx_status.set(Some(Err(l.t("tx.connect_wallet_first"))));
             return;
         }
 
@@ -72,23 +78,23 @@ pub fn EditProfilePage() -> impl IntoView {
                 let pubkey_bytes: [u8; 32] =
                     crate::pages::character_select::bs58_decode(&pubkey_str)?
                         .try_into()
-                        .map_err(|_| "Invalid pubkey length".to_string())?;
+                        .map_err(|_| l.t("tx.invalid_pubkey"))?;
                 let pubkey = solana_pubkey::Pubkey::new_from_array(pubkey_bytes);
 
                 let profile_uri = if let Some(bytes) = avatar_data {
-                    tx_status.set(Some(Ok("Uploading profile picture...".into())));
+                    tx_status.set(Some(Ok(l.t("tx.uploading_picture"))));
                     let img_url = wallet::upload_bytes_to_irys(&bytes, "image/png").await
-                        .map_err(|e| format!("Failed to upload image: {}", e))?;
+                        .map_err(|e| l.format("tx.image_upload_failed", &[("error", e.into())]))?;
                     
                     let metadata = format!(
                         "{{\"name\":\"Mojo Profile\",\"image\":\"{}\"}}",
                         img_url
                     );
-                    tx_status.set(Some(Ok("Uploading metadata...".into())));
+                    tx_status.set(Some(Ok(l.t("tx.uploading_metadata"))));
                     wallet::upload_to_irys(&metadata, "application/json").await
                         .map_err(|e| format!("Failed to upload metadata: {}", e))?
                 } else {
-                    tx_status.set(Some(Ok("Creating profile...".into())));
+                    tx_status.set(Some(Ok(l.t("tx.creating_profile"))));
                     let json = "{\"name\":\"Mojo Profile\",\"image\":\"\"}".to_string();
                     wallet::upload_to_irys(&json, "application/json").await
                         .map_err(|e| format!("Failed to upload metadata: {}", e))?
@@ -100,7 +106,7 @@ pub fn EditProfilePage() -> impl IntoView {
                     "Mojo Profile",
                     &profile_uri,
                 )
-                .map_err(|e| format!("Build tx: {}", e))?;
+                .map_err(|e| l.format("tx.build_failed", &[("error", e.to_string().into())]))?;
 
                 let sig = solana_bridge::send_transaction_bundle(bundle).await?;
                 Ok::<String, String>(sig)
@@ -109,11 +115,12 @@ pub fn EditProfilePage() -> impl IntoView {
 
             match result {
                 Ok(sig) => {
-                    tx_status.set(Some(Ok(format!(
-                        "Profile saved! Tx: {}...{}",
+                    let tx = format!(
+                        "{}...{}",
                         &sig[..sig.len().min(8)],
                         &sig[sig.len().saturating_sub(8)..]
-                    ))));
+                    );
+                    tx_status.set(Some(Ok(l.format("tx.profile_saved", &[("tx", tx.into())]))));
                 }
                 Err(e) => tx_status.set(Some(Err(e))),
             }
@@ -121,11 +128,11 @@ pub fn EditProfilePage() -> impl IntoView {
         });
     };
 
-    let all_characters = CharacterTemplate::all();
+    let custom = expect_context::<RwSignal<CustomCharacters>>().get_untracked();
 
     view! {
         <div class="page page-enter">
-            <h2 class="section-title">"EDIT PROFILE"</h2>
+            <h2 class="section-title">{move || locale.get().t("profile.title")}</h2>
 
             <div class="profile-section">
                 <div class="avatar-area" on:click=on_avatar_click>
@@ -149,17 +156,18 @@ pub fn EditProfilePage() -> impl IntoView {
                     }}
                 </div>
                 <p style="text-align: center; font-size: 0.8rem; color: var(--text-dim);">
-                    "Click to upload profile picture"
+                    {move || locale.get().t("profile.upload_hint")}
                 </p>
 
                 <div class="panel panel-glow">
-                    <h3 class="section-title" style="font-size: 0.9rem;">"OWNED CHARACTERS"</h3>
+                    <h3 class="section-title" style="font-size: 0.9rem;">{move || locale.get().t("profile.owned")}</h3>
                     <div class="owned-characters">
                         {move || {
+                            let l = locale.get();
                             if !wallet_state.get().connected {
                                 return view! {
                                     <p style="color: var(--text-dim); font-size: 0.8rem;">
-                                        "Connect wallet to view characters"
+                                        {l.t("profile.connect_to_view")}
                                     </p>
                                 }.into_any();
                             }
@@ -167,16 +175,65 @@ pub fn EditProfilePage() -> impl IntoView {
                             if minted.characters.is_empty() {
                                 return view! {
                                     <p style="color: var(--text-dim); font-size: 0.8rem;">
-                                        "No characters found — mint one from the Characters page!"
+                                        {l.t("profile.no_characters")}
                                     </p>
                                 }.into_any();
                             }
                             let cards = minted.characters.iter().map(|ch| {
                                 let name = ch.name.clone();
-                                let template = all_characters.get(ch.index).cloned();
-                                let stats = template.map(|t| format!("HP:{} ATK:{} DEF:{}", t.hp, t.atk, t.def))
-                                    .unwrap_or_default();
-                                let svg_raw = svg_metadata::generate_character_svg(ch.index, &ch.name);
+                                let level = ch.level();
+                                let template = custom.template(ch.index).map(|t| t.at_level(level).equip(&ch.loadout));
+                                let stats = template.as_ref().map(|t| l.format("stats.short", &[
+                                    ("hp", t.hp.into()),
+                                    ("atk", t.atk.into()),
+                                    ("def", t.def.into()),
+                                ])).unwrap_or_default();
+                                let progress = if level < progression::MAX_LEVEL {
+                                    l.format("profile.progress", &[
+                                        ("level", level.into()),
+                                        ("xp", ch.xp.into()),
+                                        ("next", progression::xp_for_level(level + 1).into()),
+                                    ])
+                                } else {
+                                    l.format("profile.max_level", &[("level", level.into())])
+                                };
+                                // One picker per slot, offering what this level has unlocked
+                                let index = ch.index;
+                                let gear = Slot::all().into_iter().map(|slot| {
+                                    let current = ch.loadout.get(slot).cloned().unwrap_or_default();
+                                    let equipped = Equipment::by_id(&current).map(|e| e.summary(l));
+                                    let options = Equipment::unlocked(slot, level).into_iter().map(|e| {
+                                        let selected = e.id == current;
+                                        let summary = e.summary(l);
+                                        view! {
+                                            <option value={e.id.clone()} selected=selected title={summary}>{e.name(l)}</option>
+                                        }
+                                    }).collect::<Vec<_>>();
+                                    let on_change = move |ev| {
+                                        let id = event_target_value(&ev);
+                                        minted_chars.update(|m| {
+                                            if let Some(c) = m.characters.iter_mut().find(|c| c.index == index) {
+                                                c.loadout.set(slot, (!id.is_empty()).then_some(id));
+                                            }
+                                        });
+                                    };
+                                    view! {
+                                        <div class="metadata-field equipment-slot">
+                                            <span class="metadata-label">{slot.label(l)}</span>
+                                            <select class="equipment-select" on:change=on_change>
+                                                <option value="" selected=current.is_empty()>{l.t("profile.no_equipment")}</option>
+                                                {options}
+                                            </select>
+                                            {equipped.map(|summary| view! {
+                                                <span class="equipment-summary">{summary}</span>
+                                            })}
+                                        </div>
+                                    }
+                                }).collect::<Vec<_>>();
+                                let svg_raw = svg_metadata::generate_character_svg(
+                                    template.as_ref().map_or(0, |t| t.hue),
+                                    &ch.name,
+                                );
                                 let svg_b64 = svg_metadata::base64_encode(svg_raw.as_bytes());
                                 let img_src = format!("data:image/svg+xml;base64,{}", svg_b64);
                                 view! {
@@ -186,6 +243,8 @@ pub fn EditProfilePage() -> impl IntoView {
                                         </div>
                                         <div class="character-name">{name}</div>
                                         <div class="character-stats">{stats}</div>
+                                        <div class="character-stats">{progress}</div>
+                                        {gear}
                                     </div>
                                 }
                             }).collect::<Vec<_>>();
@@ -194,12 +253,29 @@ pub fn EditProfilePage() -> impl IntoView {
                     </div>
                 </div>
 
+                <div class="panel panel-glow">
+                    <h3 class="section-title" style="font-size: 0.9rem;">{move || locale.get().t("profile.inventory")}</h3>
+                    {move || {
+                        if !wallet_state.get().connected {
+                            return None;
+                        }
+                        let l = locale.get();
+                        let inv = inventory.get();
+                        Some(ItemKind::all().into_iter().map(|kind| view! {
+                            <div class="metadata-field" title={kind.description(l)}>
+                                <span class="metadata-label">{kind.label(l)}</span>
+                                <span class="metadata-value">{l.format("count", &[("count", inv.count(kind).into())])}</span>
+                            </div>
+                        }).collect::<Vec<_>>())
+                    }}
+                </div>
+
                 <button
                     class="btn"
                     on:click=on_save_profile
                     disabled=move || saving.get() || !wallet_state.get().connected
                 >
-                    {move || if saving.get() { "SAVING..." } else { "SAVE PROFILE" }}
+                    {move || locale.get().t(if saving.get() { "profile.saving" } else { "profile.save" })}
                 </button>
 
                 {move || tx_status.get().map(|status| {
@@ -1,61 +1,183 @@
 use leptos::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
-use crate::app::{MintedCharacters, SelectedCharacter, WalletState};
+use crate::app::{
+    CampaignState, CustomCharacters, HotSeatState, MintedCharacters, RivalState,
+    SelectedCharacter, SelectedEnemy, TurnClock, WalletState,
+};
+use crate::campaign::CampaignRun;
 use crate::components::health_bar::HealthBar;
+use crate::components::status_badges::StatusBadges;
 use crate::components::stick_figure::StickFigure;
-use crate::game_state::{BattleResult, BattleState, CharacterTemplate, LogKind, PlayerState, Turn};
+use crate::game_state::{
+    self, Action, BattleResult, BattleState, CharacterTemplate, EnemyTemplate, LogKind,
+    PlayerState, Turn,
+};
+use crate::initiative::CombatantId;
+use crate::items::{Inventory, ItemKind};
+use crate::locale::{Arg, Locale};
+use crate::progression;
+use crate::replay::{self, BattleReplay};
+use crate::rival::{self, RivalResult};
+use crate::score::ScoreBreakdown;
 use crate::solana_bridge;
 
+/// Turn clock lengths offered, in seconds (0 is off).
+const CLOCK_CHOICES: [u32; 4] = [0, 10, 20, 30];
+/// How often the turn clock ticks down, in milliseconds.
+const CLOCK_TICK_MS: u32 = 100;
+
 #[component]
 pub fn GameSessionPage() -> impl IntoView {
     let selected_char = expect_context::<RwSignal<SelectedCharacter>>();
+    let selected_enemy = expect_context::<RwSignal<SelectedEnemy>>();
     let wallet_state = expect_context::<RwSignal<WalletState>>();
     let minted_chars = expect_context::<RwSignal<MintedCharacters>>();
+    let inventory = expect_context::<RwSignal<Inventory>>();
+    let campaign_state = expect_context::<RwSignal<CampaignState>>();
+    let hot_seat = expect_context::<RwSignal<HotSeatState>>();
+    let rival_state = expect_context::<RwSignal<RivalState>>();
+    let custom = expect_context::<RwSignal<CustomCharacters>>();
+    let locale = expect_context::<RwSignal<Locale>>();
+    let turn_clock = expect_context::<RwSignal<TurnClock>>();
 
-    // Gate: must have wallet + must have minted the selected character
+    // A finished run stays around for its summary until the next visit
+    campaign_state.update(|c| {
+        if c.run.as_ref().is_some_and(|r| r.over) {
+            c.run = None;
+        }
+    });
+
+    // Gate: must have wallet + must have minted the selected character.
+    // Local PvP never touches the chain, so it skips the gate.
     let gate_msg = Signal::derive(move || {
+        if hot_seat.get().rival.is_some() {
+            return None;
+        }
         let ws = wallet_state.get();
         if !ws.connected {
-            return Some("Connect your wallet to battle!");
+            return Some("battle.gate_wallet");
         }
         let idx = selected_char.get().index.unwrap_or(usize::MAX);
         let minted = minted_chars.get();
         if !minted.characters.iter().any(|c| c.index == idx) {
-            return Some("Mint this character first before battling!");
+            return Some("battle.gate_mint");
         }
         None
     });
 
-    let characters = CharacterTemplate::all();
-    let idx = selected_char.get_untracked().index.unwrap_or(0);
-    let character = characters[idx].clone();
+    // Next fight: a hot-seat match between two base characters, another
+    // wallet's snapshot, the campaign's current encounter (with carried HP)
+    // or the selected opponent. Only single battles bring the whole party,
+    // against as many opponents: the selected one and those after it.
+    // Returns the character indices fighting, their leveled and equipped
+    // templates, the hot-seat rival and the battle.
+    let next_battle = move || {
+        let sel = selected_char.get_untracked();
+        let roster = custom.get_untracked();
+        // The selected lead, or the first god if it's gone
+        let lead = sel.index.filter(|&i| roster.template(i).is_some()).unwrap_or(0);
+        let character = |i: usize| {
+            roster
+                .template(i)
+                .unwrap_or_else(|| CharacterTemplate::all().remove(0))
+        };
+        if let Some(rival) = hot_seat.get_untracked().rival {
+            let first = character(lead);
+            let second = character(rival);
+            let battle = BattleState::hot_seat(&first, &second, game_state::random_seed());
+            return (vec![lead], vec![first], Some(second), battle);
+        }
+        let minted = minted_chars.get_untracked();
+        let run = campaign_state.get_untracked().run;
+        let rival = rival_state.get_untracked().rival.map(|r| r.enemy);
+        let single = run.is_none() && rival.is_none();
+        let lineup: Vec<usize> = std::iter::once(lead)
+            .chain(sel.party.iter().copied().filter(|&i| {
+                single
+                    && roster.template(i).is_some()
+                    && minted.characters.iter().any(|c| c.index == i)
+            }))
+            .collect();
+        let party: Vec<CharacterTemplate> = lineup
+            .iter()
+            .map(|&i| {
+                character(i)
+                    .at_level(minted.level_of(i))
+                    .equip(&minted.loadout_of(i))
+            })
+            .collect();
+        let enemies = match rival.or_else(|| run.as_ref().and_then(|r| r.encounter())) {
+            Some(enemy) => vec![enemy],
+            None => {
+                let all = EnemyTemplate::all();
+                let first = selected_enemy.get_untracked().index.min(all.len() - 1);
+                (0..party.len())
+                    .map(|k| all[(first + k) % all.len()].clone())
+                    .collect()
+            }
+        };
+        let mut battle = BattleState::team_battle(&party, &enemies, game_state::random_seed())
+            .with_inventory(inventory.get_untracked());
+        if let Some(hp) = run.and_then(|r| r.carried_hp) {
+            battle = battle.with_player_hp(hp);
+        }
+        (lineup, party, None, battle)
+    };
+    let (lineup, party, rival, first_battle) = next_battle();
 
-    let battle = RwSignal::new(BattleState::new(&character));
-    let player_animating = RwSignal::new(false);
-    let enemy_animating = RwSignal::new(false);
-    let defending = RwSignal::new(false);
-    let player_hit = RwSignal::new(false);
-    let enemy_hit = RwSignal::new(false);
+    let battle = RwSignal::new(first_battle);
+    // Who the current battle started with, for XP and its replay
+    let battle_lineup = RwSignal::new(lineup);
+    let battle_party = RwSignal::new(party);
+    let battle_rival = RwSignal::new(rival);
+    // XP from the last victory and whether it brought a level up
+    let xp_gained = RwSignal::new(Option::<(u32, bool)>::None);
+    // Fighter mid-attack, and who the last strike landed on and how (hit,
+    // crit or miss)
+    let animating = RwSignal::new(Option::<CombatantId>::None);
+    let hit = RwSignal::new(Option::<(CombatantId, LogKind)>::None);
+    // Milliseconds left on the turn clock while it runs. Bumping
+    // `clock_id` stops a running countdown.
+    let clock_left = RwSignal::new(Option::<u32>::None);
+    let clock_id = RwSignal::new(0u32);
+    let stop_clock = move || {
+        clock_id.update(|id| *id += 1);
+        clock_left.set(None);
+    };
     let state_created = RwSignal::new(false);
     let chain_status = RwSignal::new(Option::<String>::None);
 
-    let player_hp = Signal::derive(move || battle.get().player_hp);
-    let player_max_hp = Signal::derive(move || battle.get().player_max_hp);
-    let enemy_hp = Signal::derive(move || battle.get().enemy_hp);
-    let enemy_max_hp = Signal::derive(move || battle.get().enemy_max_hp);
-    let player_label = Signal::derive({
-        let name = character.name.clone();
-        move || name.clone()
-    });
-    let enemy_label = Signal::derive(move || "Dark Knight".to_string());
+    // Members on the field per side; the stage only redraws when it changes
+    let field = Memo::new(move |_| battle.with(|b| b.sides.clone().map(|t| t.field)));
+
+    // How the last strike on `id` landed, while its flash is showing
+    let hit_on = move |id: CombatantId| hit.get().filter(|(at, _)| *at == id).map(|(_, kind)| kind);
 
-    let is_player_turn = move || battle.get().turn == Turn::Player && battle.get().result.is_none();
+    // A person is up: the solo player, or either player in hot-seat
+    let is_player_turn = move || battle.get().awaiting_input();
+    // Whose abilities are shown: the fighter to move when it's a person's
+    // turn, otherwise the player's lead
+    let controls_fighter = move || {
+        let b = battle.get();
+        if b.awaiting_input() {
+            b.acting_id()
+        } else {
+            CombatantId::new(Turn::Player, 0)
+        }
+    };
 
-    // Save state to chain (create or write)
-    let save_state_to_chain = move |battle_snap: BattleState, is_create: bool| {
+    // Save state to chain (create or write). A finished battle comes with
+    // the replay saved for it.
+    let save_state_to_chain = move |battle_snap: BattleState,
+                                    saved: Option<BattleReplay>,
+                                    is_create: bool| {
         let ws = wallet_state.get_untracked();
-        if !ws.connected {
+        if !ws.connected || battle_snap.is_hot_seat() {
+            return;
+        }
+        // A rival fight is only posted when the player asked for it
+        if rival_state.get_untracked().rival.is_some_and(|r| !r.post_result) {
             return;
         }
         // Only save if character is actually minted
@@ -64,30 +186,43 @@ pub fn GameSessionPage() -> impl IntoView {
             return;
         }
         let pubkey_str = ws.pubkey.unwrap_or_default();
+        let l = locale.get_untracked();
+        // In a campaign the run score (earlier encounters plus this one) is saved
+        let run = campaign_state.get_untracked().run;
         spawn_local(async move {
             let result = async {
                 let pubkey_bytes: [u8; 32] = crate::pages::character_select::bs58_decode(&pubkey_str)?
                     .try_into()
-                    .map_err(|_| "Invalid pubkey length".to_string())?;
+                    .map_err(|_| l.t("tx.invalid_pubkey"))?;
                 let pubkey = solana_pubkey::Pubkey::new_from_array(pubkey_bytes);
 
-                let player_state = PlayerState::from_battle(&battle_snap);
+                let mut player_state = PlayerState::from_battle(&battle_snap);
+                // Never post a result its saved replay can't reproduce...
+                if saved.is_some_and(|r| !r.verify(&player_state)) {
+                    return Err(l.t("tx.replay_unverified"));
+                }
+                // ...and the same goes for every encounter banked before it
+                if let Some(run) = &run {
+                    player_state.score += run
+                        .verified_score()
+                        .ok_or_else(|| l.t("tx.replay_unverified"))?;
+                }
                 let state_bytes = player_state.serialize_state();
 
                 let bundle = if is_create {
                     mojo_rust_sdk::world::World::build_create_state_tx(
                         pubkey,
-                        "mojo_battle",
+                        rival::STATE_NAME,
                         &state_bytes,
                     )
                 } else {
                     mojo_rust_sdk::world::World::build_write_state_tx(
                         pubkey,
-                        "mojo_battle",
+                        rival::STATE_NAME,
                         &state_bytes,
                     )
                 }
-                .map_err(|e| format!("Build state tx: {}", e))?;
+                .map_err(|e| l.format("tx.build_state_failed", &[("error", e.to_string().into())]))?;
 
                 let sig = solana_bridge::send_transaction_bundle(bundle).await?;
                 Ok::<String, String>(sig)
@@ -99,174 +234,600 @@ pub fn GameSessionPage() -> impl IntoView {
                     if is_create {
                         state_created.set(true);
                     }
-                    chain_status.set(Some(format!(
-                        "State saved! Tx: {}...{}",
+                    let tx = format!(
+                        "{}...{}",
                         &sig[..sig.len().min(8)],
                         &sig[sig.len().saturating_sub(8)..]
-                    )));
+                    );
+                    chain_status.set(Some(l.format("tx.state_saved", &[("tx", tx.into())])));
                 }
                 Err(e) => {
-                    chain_status.set(Some(format!("Chain error: {}", e)));
+                    chain_status.set(Some(l.format("tx.chain_error", &[("error", e.into())])));
                 }
             }
         });
     };
 
+    // Bring the wallet's items into a battle that hasn't started yet
+    // (e.g. the wallet was connected on this page)
+    Effect::new(move || {
+        let inv = inventory.get();
+        let stale = battle.with_untracked(|b| {
+            !b.is_hot_seat() && b.actions.is_empty() && b.starting_inventory != inv
+        });
+        if stale {
+            battle.update(|b| {
+                b.starting_inventory = inv.clone();
+                b.sides[0].inventory = inv;
+            });
+        }
+    });
+
+    // Items used, revives spent and loot carry over to the wallet
+    let sync_inventory = move || {
+        let b = battle.get_untracked();
+        if !b.is_hot_seat() {
+            inventory.set(b.side(Turn::Player).inventory.clone());
+        }
+    };
+
+    // Every minted member of the party earns XP for every enemy beaten
+    let award_xp = move |battle_snap: &BattleState| {
+        let xp = battle_snap.enemies.iter().map(progression::victory_xp).sum();
+        let lineup = battle_lineup.get_untracked();
+        let mut leveled_up = false;
+        minted_chars.update(|m| {
+            for c in m.characters.iter_mut().filter(|c| lineup.contains(&c.index)) {
+                let before = c.level();
+                c.xp += xp;
+                leveled_up |= c.level() > before;
+            }
+        });
+        xp_gained.set(Some((xp, leveled_up)));
+    };
+
+    // Battle over: keep a replay so it can be re-watched, carry items over,
+    // award XP for a victory and save the final state. A hot-seat match
+    // only keeps the replay.
+    let finish_battle = move |battle_snap: BattleState| {
+        let rec = BattleReplay::from_battle(
+            &battle_party.get_untracked(),
+            battle_rival.get_untracked().as_ref(),
+            &battle_snap,
+        );
+        replay::save(rec.clone());
+        if battle_snap.is_hot_seat() {
+            return;
+        }
+        if let Some(r) = rival_state.get_untracked().rival {
+            rival::record(RivalResult {
+                pubkey: r.pubkey,
+                victory: battle_snap.result == Some(BattleResult::Victory),
+                score: battle_snap.score,
+            });
+        }
+        sync_inventory();
+        if battle_snap.result == Some(BattleResult::Victory) {
+            award_xp(&battle_snap);
+        }
+        save_state_to_chain(battle_snap.clone(), Some(rec.clone()), false);
+        campaign_state.update(|c| {
+            if let Some(run) = c.run.as_mut() {
+                run.record(&battle_snap, rec.clone());
+            }
+        });
+    };
+
     // Create initial state on battle start
     {
         let battle_snap = battle.get_untracked();
         let is_create = !state_created.get_untracked();
-        save_state_to_chain(battle_snap, is_create);
+        save_state_to_chain(battle_snap, None, is_create);
     }
 
     let do_enemy_turn = move || {
-        let is_def = defending.get_untracked();
         spawn_local(async move {
-            // Delay before enemy acts
-            gloo_timers::future::TimeoutFuture::new(600).await;
-            enemy_animating.set(true);
-            gloo_timers::future::TimeoutFuture::new(600).await;
-            enemy_animating.set(false);
+            // The AI keeps acting while the player is stunned or outpaced
+            loop {
+                // Delay before the AI acts
+                gloo_timers::future::TimeoutFuture::new(600).await;
 
-            battle.update(|b| {
-                b.enemy_attack(is_def);
-            });
+                // The AI picks (and aims) now, acts after the animation
+                let mut action = None;
+                battle.update(|b| action = b.ai_action());
+                let Some(action) = action else {
+                    break;
+                };
+                let actor = battle.get_untracked().acting_id();
+                if action != Action::Defend {
+                    animating.set(Some(actor));
+                    gloo_timers::future::TimeoutFuture::new(600).await;
+                    animating.set(None);
+                }
 
-            // Player hit flash
-            player_hit.set(true);
-            gloo_timers::future::TimeoutFuture::new(300).await;
-            player_hit.set(false);
+                let mut acted = false;
+                let mut impact = None;
+                battle.update(|b| {
+                    acted = b.act(action);
+                    impact = b.last_impact.clone();
+                });
+                if !acted {
+                    break;
+                }
 
-            defending.set(false);
+                if impact.is_some() {
+                    // Defender hit flash
+                    hit.set(impact);
+                    gloo_timers::future::TimeoutFuture::new(300).await;
+                    hit.set(None);
+                }
+            }
+            sync_inventory();
 
             // If battle ended, save final state
             let snap = battle.get_untracked();
             if snap.result.is_some() {
-                save_state_to_chain(snap, false);
+                finish_battle(snap);
             }
         });
     };
 
-    let on_attack = move |_| {
+    // Take the side to move's action, animating strikes, then hand over to
+    // the AI (or the other player)
+    let on_action = move |action: Action| {
         if !is_player_turn() {
             return;
         }
-        player_animating.set(true);
+        // Aiming is free, so the clock keeps running
+        if !matches!(action, Action::Target(_)) {
+            stop_clock();
+        }
+        // The turn this action is for. A double click, or the clock running
+        // out mid-animation, must not spill over into whoever acts next.
+        let turn = battle.with_untracked(|b| (b.acting_id(), b.events.len()));
+        let strikes = matches!(action, Action::Attack | Action::Ability(_));
         spawn_local(async move {
-            gloo_timers::future::TimeoutFuture::new(600).await;
-            player_animating.set(false);
+            if strikes {
+                animating.set(Some(turn.0));
+                gloo_timers::future::TimeoutFuture::new(600).await;
+                animating.set(None);
+            }
 
+            let mut used = false;
+            let mut impact = None;
             battle.update(|b| {
-                b.player_attack();
+                if b.awaiting_input() && (b.acting_id(), b.events.len()) == turn {
+                    used = b.act(action);
+                    impact = b.last_impact.clone();
+                }
             });
+            if !used {
+                return;
+            }
+            if matches!(action, Action::Item(_)) {
+                sync_inventory();
+            }
 
-            // Enemy hit flash
-            enemy_hit.set(true);
-            gloo_timers::future::TimeoutFuture::new(300).await;
-            enemy_hit.set(false);
+            if impact.is_some() {
+                hit.set(impact);
+                gloo_timers::future::TimeoutFuture::new(300).await;
+                hit.set(None);
+            }
 
             let snap = battle.get_untracked();
             if snap.result.is_some() {
                 // Battle ended — save final state
-                save_state_to_chain(snap, false);
-            } else {
+                finish_battle(snap);
+            } else if !snap.awaiting_input() {
                 do_enemy_turn();
             }
         });
     };
 
-    let on_defend = move |_| {
-        if !is_player_turn() {
+    // Start the clock on each person's turn: the battle is on screen and
+    // someone has to move. When it runs out their default action is played.
+    let clock_turn = Memo::new(move |_| {
+        let b = battle.get();
+        (b.awaiting_input() && gate_msg.get().is_none()).then_some((b.seed, b.events.len()))
+    });
+    Effect::new(move || {
+        let turn = clock_turn.get();
+        let TurnClock {
+            seconds,
+            on_timeout,
+        } = turn_clock.get();
+        stop_clock();
+        if turn.is_none() || seconds == 0 {
             return;
         }
-        defending.set(true);
-        battle.update(|b| {
-            b.player_defend();
+        let id = clock_id.get_untracked();
+        let mut left = seconds * 1000;
+        clock_left.set(Some(left));
+        spawn_local(async move {
+            while left > 0 {
+                gloo_timers::future::TimeoutFuture::new(CLOCK_TICK_MS).await;
+                // Stopped, or the page is gone
+                if clock_id.try_get_untracked() != Some(id) {
+                    return;
+                }
+                left = left.saturating_sub(CLOCK_TICK_MS);
+                clock_left.set(Some(left));
+            }
+            on_action(on_timeout);
         });
-        do_enemy_turn();
-    };
+    });
 
-    let on_restart = move |_| {
-        let chars = CharacterTemplate::all();
-        let i = selected_char.get_untracked().index.unwrap_or(0);
-        let new_battle = BattleState::new(&chars[i]);
+    // Play again, or move on to the next campaign encounter
+    let start_next_battle = move || {
+        let (lineup, party, rival, new_battle) = next_battle();
+        battle_lineup.set(lineup);
+        battle_party.set(party);
+        battle_rival.set(rival);
         battle.set(new_battle.clone());
-        defending.set(false);
+        xp_gained.set(None);
         chain_status.set(None);
         // On restart, write (not create) since state account already exists
-        save_state_to_chain(new_battle, false);
+        save_state_to_chain(new_battle, None, false);
+        if !battle.get_untracked().awaiting_input() {
+            do_enemy_turn();
+        }
+    };
+
+    // One side's roster: name, HP and statuses per member. Reserves and
+    // knocked-out members are dimmed; a person picking a target clicks a
+    // foe on the field.
+    let team_panel = move |side: Turn| {
+        view! {
+            <div class="combatant">
+                {move || {
+                    let l = locale.get();
+                    let b = battle.get();
+                    let team = b.side(side);
+                    let solo = team.members.len() == 1;
+                    let aiming = b.awaiting_input() && b.turn == side.opponent();
+                    let target = aiming.then(|| b.target_of(b.turn));
+                    let lineup = battle_lineup.get();
+                    team.members.iter().enumerate().map(|(m, c)| {
+                        let id = CombatantId::new(side, m);
+                        let on_field = team.field.contains(&m);
+                        let mut name = match side {
+                            _ if b.is_hot_seat() => l.format(
+                                "battle.hot_seat_name",
+                                &[("number", (side.index() + 1).into()), ("name", c.name.as_str().into())],
+                            ),
+                            Turn::Player => {
+                                let level = minted_chars.get().level_of(lineup.get(m).copied().unwrap_or(usize::MAX));
+                                l.format("battle.name_level", &[("name", c.name.as_str().into()), ("level", level.into())])
+                            }
+                            Turn::Enemy => b.fighter_name(id, l),
+                        };
+                        if !solo {
+                            if c.hp <= 0 {
+                                name = l.format("battle.knocked_out", &[("name", name.into())]);
+                            } else if !on_field {
+                                name = l.format("battle.reserve", &[("name", name.into())]);
+                            } else if b.result.is_none() && b.acting_id() == id {
+                                name = format!("▶ {}", name);
+                            }
+                        }
+                        let class = if c.hp <= 0 && !solo {
+                            "team-member knocked-out"
+                        } else if !on_field {
+                            "team-member reserve"
+                        } else if target == Some(id) && team.field.len() > 1 {
+                            "team-member aimable targeted"
+                        } else if aiming && team.field.len() > 1 {
+                            "team-member aimable"
+                        } else {
+                            "team-member"
+                        };
+                        let pick = aiming && on_field && team.field.len() > 1;
+                        view! {
+                            <div class={class} on:click=move |_| if pick { on_action(Action::Target(m as u8)) }>
+                                <span class="combatant-name">{name}</span>
+                                <HealthBar current=c.hp max=c.max_hp label=l.t("stat.hp") />
+                                <StatusBadges effects=c.status.clone() />
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()
+                }}
+            </div>
+        }
     };
 
+    // A faster enemy opens the fight
+    if !battle.get_untracked().awaiting_input() {
+        do_enemy_turn();
+    }
+
     view! {
         <div class="page page-enter">
             // Gate check — show message if wallet not connected or character not minted
             {move || gate_msg.get().map(|msg| view! {
                 <div class="panel panel-glow" style="text-align:center;padding:3rem;display:flex;flex-direction:column;gap:1.5rem;">
-                    <p style="color:var(--yellow);font-size:1.1rem;">{msg}</p>
-                    <a href="/select"><button class="btn">"← Mint a Character"</button></a>
+                    <p style="color:var(--yellow);font-size:1.1rem;">{move || locale.get().t(msg)}</p>
+                    <a href="/select"><button class="btn">{move || locale.get().t("battle.mint_link")}</button></a>
                 </div>
             })}
             // Battle UI — only rendered when gate passes
             <div class="battle-arena" style:display=move || if gate_msg.get().is_some() { "none" } else { "flex" }>
+                {move || rival_state.get().rival.map(|r| view! {
+                    <div class="turn-indicator">
+                        {move || locale.get().format(
+                            if r.post_result { "battle.rival_posting" } else { "battle.rival" },
+                            &[("pubkey", r.pubkey.as_str().into())],
+                        )}
+                    </div>
+                })}
+                {move || campaign_state.get().run.map(|run| {
+                    let b = battle.get();
+                    // Once recorded, the finished encounter counts as banked
+                    let (encounter, run_score) = if b.result.is_some() {
+                        (run.stages.len(), run.banked_score())
+                    } else {
+                        (run.stage + 1, run.banked_score() + b.score)
+                    };
+                    view! {
+                        <div class="turn-indicator">
+                            {locale.get().format("battle.campaign", &[
+                                ("encounter", encounter.into()),
+                                ("total", run.stage_count().into()),
+                                ("score", run_score.into()),
+                            ])}
+                        </div>
+                    }
+                })}
                 <div class="turn-indicator">
                     {move || {
+                        let l = locale.get();
                         let b = battle.get();
+                        let name = ("name", Arg::from(b.fighter_name(b.acting_id(), l)));
                         if b.result.is_some() {
-                            "Battle Over".to_string()
-                        } else if b.turn == Turn::Player {
-                            "Your Turn".to_string()
+                            l.t("battle.over")
+                        } else if b.is_hot_seat() {
+                            // Make it obvious whose hands should be on the controls
+                            l.format("battle.hot_seat_turn", &[("number", (b.turn.index() + 1).into()), name])
+                        } else if b.turn == Turn::Enemy {
+                            l.format("battle.enemy_turn", &[name])
+                        } else if b.side(Turn::Player).members.len() > 1 {
+                            l.format("battle.your_turn_named", &[name])
                         } else {
-                            "Enemy Turn...".to_string()
+                            l.t("battle.your_turn")
                         }
                     }}
                 </div>
+                <div class="turn-order">
+                    {move || locale.get().t("battle.next")}
+                    {move || {
+                        let b = battle.get();
+                        if b.result.is_some() {
+                            return Vec::new();
+                        }
+                        b.upcoming_turns(6).into_iter().map(|id| {
+                            let class = match id.side {
+                                Turn::Player => "turn-chip player",
+                                Turn::Enemy => "turn-chip enemy",
+                            };
+                            let name = b.fighter_name(id, locale.get());
+                            view! { <span class={class}>{name}</span> }
+                        }).collect::<Vec<_>>()
+                    }}
+                </div>
 
                 <div class="battle-header">
-                    <div class="combatant">
-                        <span class="combatant-name">{player_label}</span>
-                        <HealthBar current=player_hp max=player_max_hp label=Signal::derive(|| "HP".to_string()) />
-                    </div>
-                    <span class="vs-text">"VS"</span>
-                    <div class="combatant">
-                        <span class="combatant-name">{enemy_label}</span>
-                        <HealthBar current=enemy_hp max=enemy_max_hp label=Signal::derive(|| "HP".to_string()) />
-                    </div>
+                    {team_panel(Turn::Player)}
+                    <span class="vs-text">{move || locale.get().t("vs")}</span>
+                    {team_panel(Turn::Enemy)}
                 </div>
 
                 <div class="battle-stage">
-                    <StickFigure
-                        is_enemy=false
-                        animating=Signal::derive(move || player_animating.get())
-                        defending=Signal::derive(move || defending.get())
-                        hit=Signal::derive(move || player_hit.get())
-                    />
-                    <StickFigure
-                        is_enemy=true
-                        animating=Signal::derive(move || enemy_animating.get())
-                        hit=Signal::derive(move || enemy_hit.get())
-                    />
+                    <div class="stage-side">
+                        {move || field.get()[0].iter().map(|&m| {
+                            let id = CombatantId::new(Turn::Player, m);
+                            view! {
+                                <StickFigure
+                                    is_enemy=false
+                                    weapon=battle.with_untracked(|b| b.fighter(id).weapon)
+                                    animating=Signal::derive(move || animating.get() == Some(id))
+                                    defending=Signal::derive(move || battle.with(|b| b.fighter(id).defending))
+                                    hit=Signal::derive(move || hit_on(id))
+                                />
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                    <div class="stage-side">
+                        {move || field.get()[1].iter().map(|&m| {
+                            let id = CombatantId::new(Turn::Enemy, m);
+                            view! {
+                                <StickFigure
+                                    is_enemy=true
+                                    hue=Signal::derive(move || battle.with(|b| b.enemies.get(m).map_or(0, |e| e.hue)))
+                                    animating=Signal::derive(move || animating.get() == Some(id))
+                                    defending=Signal::derive(move || battle.with(|b| b.fighter(id).defending))
+                                    hit=Signal::derive(move || hit_on(id))
+                                />
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
                 </div>
 
+                {move || clock_left.get().map(|left| {
+                    let total = turn_clock.get().seconds * 1000;
+                    let percent = left as f64 / total.max(1) as f64 * 100.0;
+                    let class = if percent > 25.0 { "turn-clock-fill" } else { "turn-clock-fill low" };
+                    view! {
+                        <div class="turn-clock">
+                            <div class={class} style=format!("width: {}%", percent)></div>
+                            <div class="turn-clock-text">
+                                {locale.get().format("clock.seconds", &[("seconds", left.div_ceil(1000).into())])}
+                            </div>
+                        </div>
+                    }
+                })}
+
+                {move || battle.with(|b| b.awaiting_input().then(|| {
+                    let l = locale.get();
+                    let (class, text) = match b.acting().combo.next_attack() {
+                        Some(combo) => ("combo-meter ready", l.format("battle.combo_ready", &[
+                            ("combo", combo.label(l).into()),
+                            ("percent", combo.bonus_percent().into()),
+                        ])),
+                        None => ("combo-meter", l.t("battle.combo_hint")),
+                    };
+                    view! { <div class={class}>{text}</div> }
+                }))}
+
                 <div class="battle-controls">
                     <button
                         class="btn"
-                        on:click=on_attack
+                        on:click=move |_| on_action(Action::Attack)
                         disabled=move || !is_player_turn()
                     >
-                        "ATTACK"
+                        {move || locale.get().t("battle.attack")}
                     </button>
                     <button
                         class="btn"
-                        on:click=on_defend
+                        on:click=move |_| on_action(Action::Defend)
                         disabled=move || !is_player_turn()
                     >
-                        "DEFEND"
+                        {move || locale.get().t("battle.defend")}
                     </button>
-                    <a href="/select"><button class="btn btn-small">"MENU"</button></a>
+                    {move || {
+                        battle.get().fighter(controls_fighter()).abilities.iter().enumerate().map(|(i, slot)| {
+                            let label = if slot.ready() {
+                                slot.ability.name(locale.get()).to_uppercase()
+                            } else {
+                                locale.get().format("battle.cooldown", &[
+                                    ("ability", slot.ability.name(locale.get()).to_uppercase().into()),
+                                    ("turns", slot.cooldown_left.into()),
+                                ])
+                            };
+                            let ready = slot.ready();
+                            view! {
+                                <button
+                                    class="btn"
+                                    title={slot.ability.description(locale.get())}
+                                    on:click=move |_| on_action(Action::Ability(i as u8))
+                                    disabled=move || !is_player_turn() || !ready
+                                >
+                                    {label}
+                                </button>
+                            }
+                        }).collect::<Vec<_>>()
+                    }}
+                    {move || {
+                        let l = locale.get();
+                        let b = battle.get();
+                        if !b.awaiting_input() {
+                            return Vec::new();
+                        }
+                        let team = b.side(b.turn);
+                        team.reserves().map(|m| {
+                            view! {
+                                <button
+                                    class="btn btn-small"
+                                    title=l.t("battle.swap_title")
+                                    on:click=move |_| on_action(Action::Swap(m as u8))
+                                >
+                                    {l.format("battle.swap", &[("name", team.members[m].name.to_uppercase().into())])}
+                                </button>
+                            }
+                        }).collect::<Vec<_>>()
+                    }}
+                    <a href="/select"><button class="btn btn-small">{move || locale.get().t("battle.menu")}</button></a>
+                </div>
+                // No items in hot-seat: both players fight with what they have
+                <div class="battle-controls" style:display=move || if battle.get().is_hot_seat() { "none" } else { "flex" }>
+                    {move || {
+                        let l = locale.get();
+                        let inv = battle.get().side(Turn::Player).inventory.clone();
+                        ItemKind::all().into_iter().filter(|k| k.usable()).map(|kind| {
+                            let count = inv.count(kind);
+                            view! {
+                                <button
+                                    class="btn btn-small"
+                                    title={kind.description(l)}
+                                    on:click=move |_| on_action(Action::Item(kind))
+                                    disabled=move || !is_player_turn() || count == 0
+                                >
+                                    {l.format("battle.item", &[("item", kind.label(l).to_uppercase().into()), ("count", count.into())])}
+                                </button>
+                            }
+                        }).collect::<Vec<_>>()
+                    }}
+                    {move || {
+                        let l = locale.get();
+                        let revives = battle.get().side(Turn::Player).inventory.count(ItemKind::Revive);
+                        (revives > 0).then(|| view! {
+                            <span class="metadata-label" title={ItemKind::Revive.description(l)}>
+                                {l.format("battle.revive", &[
+                                    ("item", ItemKind::Revive.label(l).to_uppercase().into()),
+                                    ("count", revives.into()),
+                                ])}
+                            </span>
+                        })
+                    }}
+                </div>
+
+                <div class="battle-controls">
+                    <label class="metadata-label">
+                        {move || locale.get().t("clock.label")}
+                        " "
+                        <select
+                            class="equipment-select"
+                            on:change=move |ev| {
+                                if let Ok(seconds) = event_target_value(&ev).parse() {
+                                    turn_clock.update(|t| t.seconds = seconds);
+                                }
+                            }
+                        >
+                            {CLOCK_CHOICES.into_iter().map(|seconds| view! {
+                                <option
+                                    value=seconds.to_string()
+                                    selected=move || turn_clock.get().seconds == seconds
+                                >
+                                    {move || if seconds == 0 {
+                                        locale.get().t("clock.off")
+                                    } else {
+                                        locale.get().format("clock.seconds", &[("seconds", seconds.into())])
+                                    }}
+                                </option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    </label>
+                    <label class="metadata-label">
+                        {move || locale.get().t("clock.on_timeout")}
+                        " "
+                        <select
+                            class="equipment-select"
+                            on:change=move |ev| {
+                                let action = match event_target_value(&ev).as_str() {
+                                    "attack" => Action::Attack,
+                                    _ => Action::Defend,
+                                };
+                                turn_clock.update(|t| t.on_timeout = action);
+                            }
+                        >
+                            {[(Action::Defend, "defend"), (Action::Attack, "attack")].into_iter().map(|(action, value)| view! {
+                                <option value=value selected=move || turn_clock.get().on_timeout == action>
+                                    {move || locale.get().t(&format!("battle.{}", value))}
+                                </option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    </label>
                 </div>
 
                 <div class="score-display">
-                    {move || format!("Score: {}", battle.get().score)}
+                    {move || locale.get().format("score", &[("score", battle.get().score.into())])}
+                    {move || battle.with(|b| (b.breakdown.combos > 0).then(|| {
+                        let l = locale.get();
+                        l.format("battle.combo_score", &[
+                            ("value", l.decimal(b.breakdown.combo_percent() as i64).into()),
+                        ])
+                    }))}
+                </div>
+                <div style="font-size: 0.65rem; color: var(--text-dim);">
+                    {move || locale.get().format("battle.seed", &[("seed", format!("{:016x}", battle.get().seed).into())])}
                 </div>
 
                 {move || chain_status.get().map(|msg| {
@@ -275,13 +836,15 @@ pub fn GameSessionPage() -> impl IntoView {
 
                 <div class="battle-log">
                     {move || {
-                        battle.get().log.iter().rev().map(|entry| {
+                        battle.get().log(locale.get()).into_iter().rev().map(|entry| {
                             let class = match entry.kind {
                                 LogKind::Damage => "log-entry damage",
                                 LogKind::Heal => "log-entry heal",
                                 LogKind::Info => "log-entry info",
+                                LogKind::Crit => "log-entry crit",
+                                LogKind::Miss => "log-entry miss",
                             };
-                            let msg = entry.message.clone();
+                            let msg = entry.message;
                             view! { <div class={class}>{msg}</div> }
                         }).collect::<Vec<_>>()
                     }}
@@ -290,22 +853,176 @@ pub fn GameSessionPage() -> impl IntoView {
 
             // Battle result overlay
             {move || {
+                let l = locale.get();
                 let b = battle.get();
-                b.result.map(|result| {
+                let run = campaign_state.get().run;
+                b.result.clone().map(|result| {
+                    if b.is_hot_seat() {
+                        let winner = result.winner();
+                        return view! {
+                            <div class="battle-result">
+                                <div class="result-text victory">
+                                    {l.format("battle.player_wins", &[("number", (winner.index() + 1).into())])}
+                                </div>
+                                <div class="score-display">
+                                    {l.format("battle.stands_victorious", &[("names", b.names(winner, l).into())])}
+                                </div>
+                                <button class="btn" on:click=move |_| start_next_battle()>{l.t("battle.rematch")}</button>
+                                <a href="/replays"><button class="btn">{l.t("battle.replays")}</button></a>
+                                <a href="/pvp"><button class="btn">{l.t("battle.change_fighters")}</button></a>
+                            </div>
+                        }.into_any();
+                    }
                     let (text, class) = match result {
-                        BattleResult::Victory => ("VICTORY", "result-text victory"),
-                        BattleResult::Defeat => ("DEFEAT", "result-text defeat"),
+                        BattleResult::Victory => ("result.victory", "result-text victory"),
+                        BattleResult::Defeat => ("result.defeat", "result-text defeat"),
                     };
                     view! {
                         <div class="battle-result">
-                            <div class={class}>{text}</div>
-                            <div class="score-display">{format!("Final Score: {}", b.score)}</div>
-                            <button class="btn" on:click=on_restart>"PLAY AGAIN"</button>
-                            <a href="/select"><button class="btn">"BACK TO MENU"</button></a>
+                            <div class={class}>{l.t(text)}</div>
+                            <div class="score-display">{l.format("battle.final_score", &[("score", b.score.into())])}</div>
+                            <ScoreSummary breakdown=b.breakdown.clone() locale=l />
+                            {xp_gained.get().map(|(xp, leveled_up)| view! {
+                                <div class="log-entry info">
+                                    {l.format(if leveled_up { "battle.xp_level_up" } else { "battle.xp" }, &[("xp", xp.into())])}
+                                </div>
+                            })}
+                            {b.loot.map(|kind| view! {
+                                <div class="log-entry heal">{l.format("battle.loot", &[("item", kind.label(l).into())])}</div>
+                            })}
+                            {match run {
+                                None => view! {
+                                    <button class="btn" on:click=move |_| start_next_battle()>{l.t("battle.play_again")}</button>
+                                }.into_any(),
+                                Some(run) if !run.over => view! {
+                                    <div class="score-display">
+                                        {l.format("campaign.run_score", &[("score", run.banked_score().into())])}
+                                    </div>
+                                    <button class="btn" on:click=move |_| start_next_battle()>
+                                        {l.format("battle.next_encounter", &[
+                                            ("stage", (run.stage + 1).into()),
+                                            ("total", run.stage_count().into()),
+                                        ])}
+                                    </button>
+                                }.into_any(),
+                                Some(run) => view! {
+                                    <CampaignSummary run=run.clone() locale=l />
+                                    <button class="btn" on:click=move |_| {
+                                        campaign_state.set(CampaignState { run: Some(CampaignRun::new()) });
+                                        start_next_battle();
+                                    }>{l.t("battle.new_run")}</button>
+                                }.into_any(),
+                            }}
+                            <a href="/replays"><button class="btn">{l.t("battle.replays")}</button></a>
+                            <a href="/select"><button class="btn">{l.t("battle.back_to_menu")}</button></a>
                         </div>
-                    }
+                    }.into_any()
                 })
             }}
         </div>
     }
 }
+
+/// How the final score was reached, one line per part.
+#[component]
+fn ScoreSummary(breakdown: ScoreBreakdown, locale: Locale) -> impl IntoView {
+    let row = |label: String, points: String| {
+        view! {
+            <div class="metadata-field">
+                <span class="metadata-label">{label}</span>
+                <span class="metadata-value">{points}</span>
+            </div>
+        }
+    };
+    let bonus = |points: u32| locale.format("breakdown.bonus", &[("points", points.into())]);
+    let rows = vec![
+        row(
+            locale.t("breakdown.damage_dealt"),
+            locale.number(breakdown.damage_dealt as i64),
+        ),
+        row(
+            locale.format(
+                "breakdown.damage_avoided",
+                &[("amount", breakdown.damage_avoided.into())],
+            ),
+            bonus(breakdown.avoided_points()),
+        ),
+        row(locale.t("breakdown.victory_bonus"), bonus(breakdown.victory_bonus())),
+        row(
+            locale.format(
+                "breakdown.hp_remaining",
+                &[("percent", breakdown.hp_remaining_percent.into())],
+            ),
+            bonus(breakdown.hp_bonus()),
+        ),
+        row(
+            locale.format("breakdown.speed", &[("count", breakdown.turns.into())]),
+            bonus(breakdown.speed_bonus()),
+        ),
+        row(
+            locale.t("breakdown.difficulty"),
+            locale.format(
+                "breakdown.multiplier",
+                &[("value", locale.decimal(breakdown.difficulty_percent as i64).into())],
+            ),
+        ),
+        row(
+            locale.format("breakdown.combos", &[("count", breakdown.combos.into())]),
+            locale.format(
+                "breakdown.multiplier",
+                &[("value", locale.decimal(breakdown.combo_percent() as i64).into())],
+            ),
+        ),
+    ];
+    view! { <div class="panel" style="min-width: 280px;">{rows}</div> }
+}
+
+/// End-of-run table: every encounter fought and the total score.
+#[component]
+fn CampaignSummary(run: CampaignRun, locale: Locale) -> impl IntoView {
+    let title = locale.t(if run.cleared() {
+        "campaign.cleared"
+    } else {
+        "campaign.run_over"
+    });
+    let rows = run
+        .stages
+        .iter()
+        .enumerate()
+        .map(|(i, stage)| {
+            let outcome = locale.t(if stage.victory {
+                "outcome.won"
+            } else {
+                "outcome.lost"
+            });
+            let label = locale.format(
+                "campaign.stage",
+                &[
+                    ("number", (i + 1).into()),
+                    ("enemy", stage.enemy.name(locale).into()),
+                    ("outcome", outcome.into()),
+                ],
+            );
+            let points = locale.format("points", &[("count", stage.score.into())]);
+            let result = locale.format(
+                "campaign.stage_result",
+                &[("points", points.into()), ("hp", stage.hp_left.into())],
+            );
+            view! {
+                <div class="metadata-field">
+                    <span class="metadata-label">{label}</span>
+                    <span class="metadata-value">{result}</span>
+                </div>
+            }
+        })
+        .collect::<Vec<_>>();
+    view! {
+        <div class="panel" style="min-width: 280px;">
+            <div class="score-display">{title}</div>
+            {rows}
+            <div class="score-display">
+                {locale.format("campaign.run_score", &[("score", run.banked_score().into())])}
+            </div>
+        </div>
+    }
+}
@@ -1,5 +1,10 @@
+pub mod campaign;
 pub mod character_select;
+pub mod creator;
 pub mod edit_profile;
 pub mod game_session;
 pub mod preview;
+pub mod pvp;
+pub mod replays;
+pub mod rivals;
 pub mod start;
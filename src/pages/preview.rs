@@ -1,8 +1,9 @@
 use leptos::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
-use crate::app::{CollectionState, SelectedCharacter, WalletState};
+use crate::app::{CollectionState, CustomCharacters, SelectedCharacter, WalletState};
 use crate::game_state::CharacterTemplate;
+use crate::locale::Locale;
 use crate::solana_bridge;
 use crate::wallet;
 
@@ -11,18 +12,22 @@ pub fn PreviewPage() -> impl IntoView {
     let wallet_state = expect_context::<RwSignal<WalletState>>();
     let selected_char = expect_context::<RwSignal<SelectedCharacter>>();
     let collection_state = expect_context::<RwSignal<CollectionState>>();
+    let locale = expect_context::<RwSignal<Locale>>();
     let tx_status = RwSignal::new(Option::<Result<String, String>>::None);
     let minting = RwSignal::new(false);
 
-    let characters = CharacterTemplate::all();
     let idx = selected_char.get_untracked().index.unwrap_or(0);
-    let character = characters[idx].clone();
+    let character = expect_context::<RwSignal<CustomCharacters>>()
+        .get_untracked()
+        .template(idx)
+        .unwrap_or_else(|| CharacterTemplate::all().remove(0));
     let char_for_mint = character.clone();
 
     let on_proceed = move |_| {
+        let l = locale.get_untracked();
         let ws = wallet_state.get_untracked();
         if !ws.connected {
-            tx_status.set(Some(Err("Connect wallet first".into())));
+            tx_status.set(Some(Err(l.t("tx.connect_wallet_first"))));
             return;
         }
 
@@ -35,26 +40,26 @@ pub fn PreviewPage() -> impl IntoView {
             let result = async {
                 let pubkey_bytes: [u8; 32] = crate::pages::character_select::bs58_decode(&pubkey_str)?
                     .try_into()
-                    .map_err(|_| "Invalid pubkey length".to_string())?;
+                    .map_err(|_| l.t("tx.invalid_pubkey"))?;
                 let pubkey = solana_pubkey::Pubkey::new_from_array(pubkey_bytes);
                 let collection = collection_state
                     .get_untracked()
                     .pubkey
-                    .ok_or_else(|| "No collection — mint a character first".to_string())?;
+                    .ok_or_else(|| l.t("tx.no_collection"))?;
 
-                tx_status.set(Some(Ok("Uploading image & metadata to Arweave...".into())));
+                tx_status.set(Some(Ok(l.t("tx.uploading_character"))));
                 let char_uri = wallet::upload_character_metadata(
-                    idx,
+                    ch.hue,
                     &ch.name,
-                    &ch.description,
+                    &ch.description(l),
                     ch.hp,
                     ch.atk,
                     ch.def,
                 ).await
-                .map_err(|e| format!("Arweave upload failed: {}", e))?;
-                tx_status.set(Some(Ok("Image on Arweave! Minting... (approve in Phantom)".into())));
+                .map_err(|e| l.format("tx.upload_failed", &[("error", e.into())]))?;
+                tx_status.set(Some(Ok(l.t("tx.uploaded_minting"))));
 
-                tx_status.set(Some(Ok("Minting... (approve in Phantom)".into())));
+                tx_status.set(Some(Ok(l.t("tx.minting"))));
                 let bundle = mojo_rust_sdk::world::World::build_character_tx(
                     &collection,
                     pubkey,
@@ -62,7 +67,7 @@ pub fn PreviewPage() -> impl IntoView {
                     &ch.name,
                     &char_uri,
                 )
-                .map_err(|e| format!("Build tx: {}", e))?;
+                .map_err(|e| l.format("tx.build_failed", &[("error", e.to_string().into())]))?;
 
                 let sig = solana_bridge::send_transaction_bundle(bundle).await?;
                 Ok::<String, String>(sig)
@@ -71,11 +76,8 @@ pub fn PreviewPage() -> impl IntoView {
 
             match result {
                 Ok(sig) => {
-                    tx_status.set(Some(Ok(format!(
-                        "Minted! Tx: {}...{}",
-                        &sig[..8],
-                        &sig[sig.len().saturating_sub(8)..]
-                    ))));
+                    let tx = format!("{}...{}", &sig[..8], &sig[sig.len().saturating_sub(8)..]);
+                    tx_status.set(Some(Ok(l.format("tx.minted", &[("tx", tx.into())]))));
                 }
                 Err(e) => tx_status.set(Some(Err(e))),
             }
@@ -83,11 +85,11 @@ pub fn PreviewPage() -> impl IntoView {
         });
     };
 
-    let hue = idx * 60;
+    let hue = character.hue;
 
     view! {
         <div class="page page-enter">
-            <h2 class="section-title">"CHARACTER PREVIEW"</h2>
+            <h2 class="section-title">{move || locale.get().t("preview.title")}</h2>
 
             <div class="preview-layout">
                 <div class="preview-image">
@@ -115,25 +117,34 @@ pub fn PreviewPage() -> impl IntoView {
 
                 <div class="preview-details">
                     <div class="metadata-field">
-                        <span class="metadata-label">"Name"</span>
+                        <span class="metadata-label">{move || locale.get().t("preview.name")}</span>
                         <span class="metadata-value">{character.name.clone()}</span>
                     </div>
                     <div class="metadata-field">
-                        <span class="metadata-label">"Description"</span>
-                        <span class="metadata-value">{character.description.clone()}</span>
+                        <span class="metadata-label">{move || locale.get().t("preview.description")}</span>
+                        <span class="metadata-value">{
+                            let character = character.clone();
+                            move || character.description(locale.get())
+                        }</span>
                     </div>
                     <div class="metadata-field">
-                        <span class="metadata-label">"Collection"</span>
+                        <span class="metadata-label">{move || locale.get().t("preview.collection")}</span>
                         <span class="metadata-value">"Mojo Arena Characters"</span>
                     </div>
                     <div class="metadata-field">
-                        <span class="metadata-label">"Traits"</span>
+                        <span class="metadata-label">{move || locale.get().t("preview.traits")}</span>
                         <span class="metadata-value">
-                            {format!("HP: {} | ATK: {} | DEF: {}", character.hp, character.atk, character.def)}
+                            {
+                                let (hp, atk, def) = (character.hp, character.atk, character.def);
+                                move || locale.get().format(
+                                    "preview.traits_value",
+                                    &[("hp", hp.into()), ("atk", atk.into()), ("def", def.into())],
+                                )
+                            }
                         </span>
                     </div>
                     <div class="metadata-field">
-                        <span class="metadata-label">"Cost"</span>
+                        <span class="metadata-label">{move || locale.get().t("preview.cost")}</span>
                         <span class="cost-display">"~0.01 SOL"</span>
                     </div>
 
@@ -142,7 +153,7 @@ pub fn PreviewPage() -> impl IntoView {
                         on:click=on_proceed
                         disabled=move || minting.get()
                     >
-                        {move || if minting.get() { "MINTING..." } else { "PROCEED" }}
+                        {move || locale.get().t(if minting.get() { "preview.minting" } else { "preview.proceed" })}
                     </button>
 
                     {move || tx_status.get().map(|status| {
@@ -0,0 +1,71 @@
+use leptos::prelude::*;
+
+use crate::app::{CampaignState, HotSeatState, RivalState, SelectedCharacter};
+use crate::components::character_card::CharacterCard;
+use crate::game_state::CharacterTemplate;
+use crate::locale::Locale;
+
+/// One character grid per player; `pick` is the index chosen so far.
+fn character_grid(
+    pick: Signal<Option<usize>>,
+    on_pick: impl Fn(usize) + Copy + 'static,
+) -> impl IntoView {
+    view! {
+        <div class="character-grid">
+            {CharacterTemplate::all()
+                .into_iter()
+                .enumerate()
+                .map(|(i, ch)| {
+                    let is_selected = Signal::derive(move || pick.get() == Some(i));
+                    view! {
+                        <CharacterCard
+                            character=ch
+                            selected=is_selected
+                            on_click=move |_| on_pick(i)
+                        />
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+#[component]
+pub fn PvpPage() -> impl IntoView {
+    let selected_char = expect_context::<RwSignal<SelectedCharacter>>();
+    let campaign_state = expect_context::<RwSignal<CampaignState>>();
+    let hot_seat = expect_context::<RwSignal<HotSeatState>>();
+    let rival_state = expect_context::<RwSignal<RivalState>>();
+    let locale = expect_context::<RwSignal<Locale>>();
+    let rival = RwSignal::new(hot_seat.get_untracked().rival);
+
+    let first = Signal::derive(move || selected_char.get().index);
+    let second = Signal::derive(move || rival.get());
+    let ready = move || first.get().is_some() && second.get().is_some();
+
+    view! {
+        <div class="page page-enter">
+            <h2 class="section-title">{move || locale.get().t("pvp.title")}</h2>
+            <p style="color: var(--text-dim); font-size: 0.8rem; max-width: 600px; text-align: center;">
+                {move || locale.get().t("pvp.intro")}
+            </p>
+            <h2 class="section-title">{move || locale.get().format("pvp.player", &[("number", 1u32.into())])}</h2>
+            {character_grid(first, move |i| selected_char.update(|s| s.select(i)))}
+            <h2 class="section-title">{move || locale.get().format("pvp.player", &[("number", 2u32.into())])}</h2>
+            {character_grid(second, move |i| rival.set(Some(i)))}
+            <a href="/battle">
+                <button
+                    class="btn"
+                    disabled=move || !ready()
+                    on:click=move |_| {
+                        campaign_state.set(CampaignState::default());
+                        rival_state.set(RivalState::default());
+                        hot_seat.set(HotSeatState { rival: rival.get_untracked() });
+                    }
+                >
+                    {move || locale.get().t(if ready() { "pvp.fight" } else { "pvp.both_pick" })}
+                </button>
+            </a>
+        </div>
+    }
+}
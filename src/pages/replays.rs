@@ -0,0 +1,175 @@
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::components::health_bar::HealthBar;
+use crate::components::stick_figure::StickFigure;
+use crate::game_state::{BattleResult, BattleState, LogKind, Turn};
+use crate::initiative::CombatantId;
+use crate::locale::Locale;
+use crate::replay::{self, BattleReplay};
+
+#[component]
+pub fn ReplaysPage() -> impl IntoView {
+    let locale = expect_context::<RwSignal<Locale>>();
+    let saved = replay::load_saved()
+        .into_iter()
+        .map(|rec| {
+            let outcome = replay::replay(&rec);
+            (rec, outcome)
+        })
+        .collect::<Vec<_>>();
+    let watching = RwSignal::new(Option::<BattleState>::None);
+    // Bumped on every new playback so a previous one stops stepping
+    let playback_id = RwSignal::new(0u32);
+
+    let on_watch = move |rec: BattleReplay| {
+        let id = playback_id.get_untracked() + 1;
+        playback_id.set(id);
+        watching.set(Some(rec.start()));
+        spawn_local(async move {
+            for &action in &rec.actions {
+                gloo_timers::future::TimeoutFuture::new(900).await;
+                if playback_id.get_untracked() != id {
+                    return;
+                }
+                let mut still_running = true;
+                watching.update(|w| {
+                    if let Some(b) = w.as_mut() {
+                        still_running = replay::step(b, action);
+                    }
+                });
+                if !still_running {
+                    return;
+                }
+            }
+        });
+    };
+
+    let has_rows = !saved.is_empty();
+    let rows = move || {
+        let l = locale.get();
+        saved
+            .iter()
+            .map(|(rec, outcome)| {
+                let result_text = match &outcome.result {
+                    Some(result) if outcome.battle.is_hot_seat() => l.format(
+                        "replays.wins",
+                        &[("names", outcome.battle.names(result.winner(), l).into())],
+                    ),
+                    Some(BattleResult::Victory) => l.t("result.victory"),
+                    Some(BattleResult::Defeat) => l.t("result.defeat"),
+                    None => l.t("replays.unfinished"),
+                };
+                let summary = l.format(
+                    "replays.summary",
+                    &[
+                        ("party", outcome.battle.names(Turn::Player, l).into()),
+                        ("enemies", outcome.battle.names(Turn::Enemy, l).into()),
+                        ("result", result_text.into()),
+                        ("score", outcome.score.into()),
+                        ("count", rec.actions.len().into()),
+                    ],
+                );
+                let last_line = outcome
+                    .battle
+                    .log(l)
+                    .pop()
+                    .map(|e| e.message)
+                    .unwrap_or_default();
+                let rec = rec.clone();
+                view! {
+                    <div class="metadata-field">
+                        <span class="metadata-value">{summary}</span>
+                        <span class="metadata-label">{last_line}</span>
+                        <button class="btn btn-small" on:click=move |_| on_watch(rec.clone())>
+                            {l.t("replays.watch")}
+                        </button>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="page page-enter">
+            <h2 class="section-title">{move || locale.get().t("replays.title")}</h2>
+
+            {move || watching.get().map(|b| {
+                let l = locale.get();
+                view! {
+                    <div class="battle-arena">
+                        <div class="battle-header">
+                            {roster(&b, Turn::Player, l)}
+                            <span class="vs-text">{l.t("vs")}</span>
+                            {roster(&b, Turn::Enemy, l)}
+                        </div>
+                        <div class="battle-stage">
+                            <div class="stage-side">
+                                {b.side(Turn::Player).field.iter().map(|&m| view! {
+                                    <StickFigure is_enemy=false weapon=b.side(Turn::Player).members[m].weapon />
+                                }).collect::<Vec<_>>()}
+                            </div>
+                            <div class="stage-side">
+                                {b.side(Turn::Enemy).field.iter().map(|&m| view! {
+                                    <StickFigure is_enemy=true hue=b.enemies.get(m).map_or(0, |e| e.hue) />
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        </div>
+                        <div class="score-display">{l.format("score", &[("score", b.score.into())])}</div>
+                        <div class="battle-log">
+                            {b.log(l).into_iter().rev().map(|entry| {
+                                let class = match entry.kind {
+                                    LogKind::Damage => "log-entry damage",
+                                    LogKind::Heal => "log-entry heal",
+                                    LogKind::Info => "log-entry info",
+                                    LogKind::Crit => "log-entry crit",
+                                    LogKind::Miss => "log-entry miss",
+                                };
+                                view! { <div class={class}>{entry.message}</div> }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }
+            })}
+
+            <div class="panel panel-glow" style="width: 100%; max-width: 600px;">
+                {if has_rows {
+                    view! { <div>{rows}</div> }.into_any()
+                } else {
+                    view! {
+                        <p style="color: var(--text-dim); font-size: 0.8rem;">
+                            {move || locale.get().t("replays.none")}
+                        </p>
+                    }.into_any()
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Name and HP of every member of one side, reserves and knock-outs dimmed.
+fn roster(battle: &BattleState, side: Turn, locale: Locale) -> impl IntoView {
+    let team = battle.side(side);
+    let solo = team.members.len() == 1;
+    let members = team
+        .members
+        .iter()
+        .enumerate()
+        .map(|(m, c)| {
+            let class = if c.hp <= 0 && !solo {
+                "team-member knocked-out"
+            } else if !team.field.contains(&m) {
+                "team-member reserve"
+            } else {
+                "team-member"
+            };
+            view! {
+                <div class={class}>
+                    <span class="combatant-name">{battle.fighter_name(CombatantId::new(side, m), locale)}</span>
+                    <HealthBar current=c.hp max=c.max_hp label=locale.t("stat.hp") />
+                </div>
+            }
+        })
+        .collect::<Vec<_>>();
+    view! { <div class="combatant">{members}</div> }
+}
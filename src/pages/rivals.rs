@@ -0,0 +1,125 @@
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::app::{CampaignState, HotSeatState, RivalState};
+use crate::components::enemy_card::EnemyCard;
+use crate::locale::Locale;
+use crate::rival::{self, Rival};
+
+#[component]
+pub fn RivalsPage() -> impl IntoView {
+    let campaign_state = expect_context::<RwSignal<CampaignState>>();
+    let hot_seat = expect_context::<RwSignal<HotSeatState>>();
+    let rival_state = expect_context::<RwSignal<RivalState>>();
+    let locale = expect_context::<RwSignal<Locale>>();
+
+    let pubkey_input = RwSignal::new(String::new());
+    let post_result = RwSignal::new(false);
+    let found = RwSignal::new(Option::<Rival>::None);
+    let fetching = RwSignal::new(false);
+    let error = RwSignal::new(Option::<String>::None);
+    let history = rival::load_history();
+
+    let on_fetch = move |_| {
+        let input = pubkey_input.get_untracked().trim().to_string();
+        let l = locale.get_untracked();
+        found.set(None);
+        error.set(None);
+        let owner = match rival::parse_pubkey(&input, l) {
+            Ok(owner) => owner,
+            Err(e) => {
+                error.set(Some(e));
+                return;
+            }
+        };
+        fetching.set(true);
+        spawn_local(async move {
+            match rival::fetch_snapshot(owner, l).await {
+                Ok(state) => found.set(Some(Rival {
+                    enemy: rival::opponent(&input, &state, l),
+                    pubkey: input,
+                    post_result: false,
+                })),
+                Err(e) => error.set(Some(e)),
+            }
+            fetching.set(false);
+        });
+    };
+
+    let on_fight = move |_| {
+        let Some(mut rival) = found.get_untracked() else {
+            return;
+        };
+        rival.post_result = post_result.get_untracked();
+        campaign_state.set(CampaignState::default());
+        hot_seat.set(HotSeatState::default());
+        rival_state.set(RivalState { rival: Some(rival) });
+    };
+
+    let has_rows = !history.is_empty();
+    let rows = move || {
+        let l = locale.get();
+        history
+            .iter()
+            .map(|r| {
+                let outcome = l.t(if r.victory { "outcome.won" } else { "outcome.lost" });
+                let result = l.format(
+                    "rivals.result",
+                    &[("pubkey", r.pubkey.as_str().into()), ("outcome", outcome.into())],
+                );
+                let points = l.format("points", &[("count", r.score.into())]);
+                view! {
+                    <div class="metadata-field">
+                        <span class="metadata-label">{result}</span>
+                        <span class="metadata-value">{points}</span>
+                    </div>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="page page-enter">
+            <h2 class="section-title">{move || locale.get().t("rivals.title")}</h2>
+            <p style="color: var(--text-dim); font-size: 0.8rem; max-width: 600px; text-align: center;">
+                {move || locale.get().t("rivals.intro")}
+            </p>
+            <div class="panel panel-glow" style="width: 100%; max-width: 600px; display: flex; flex-direction: column; gap: 1rem;">
+                <input
+                    class="text-input"
+                    type="text"
+                    placeholder=move || locale.get().t("rivals.pubkey_placeholder")
+                    bind:value=pubkey_input
+                />
+                <button
+                    class="btn"
+                    on:click=on_fetch
+                    disabled=move || fetching.get() || pubkey_input.get().trim().is_empty()
+                >
+                    {move || locale.get().t(if fetching.get() { "rivals.reading" } else { "rivals.fetch" })}
+                </button>
+                {move || error.get().map(|msg| view! { <div class="tx-status error">{msg}</div> })}
+            </div>
+            {move || found.get().map(|rival| view! {
+                <EnemyCard enemy=rival.enemy selected=true on_click=|_| {} />
+                <label class="metadata-label">
+                    <input type="checkbox" bind:checked=post_result />
+                    {move || locale.get().t("rivals.post_result")}
+                </label>
+                <a href="/battle">
+                    <button class="btn" on:click=on_fight>{move || locale.get().t("rivals.fight")}</button>
+                </a>
+            })}
+            <div class="panel" style="width: 100%; max-width: 600px;">
+                <div class="score-display">{move || locale.get().t("rivals.past_results")}</div>
+                {if has_rows {
+                    view! { <div>{rows}</div> }.into_any()
+                } else {
+                    view! {
+                        <p style="color: var(--text-dim); font-size: 0.8rem;">{move || locale.get().t("rivals.none")}</p>
+                    }.into_any()
+                }}
+            </div>
+        </div>
+    }
+}
@@ -3,11 +3,13 @@ use leptos_router::hooks::use_navigate;
 use wasm_bindgen_futures::spawn_local;
 
 use crate::app::WalletState;
+use crate::locale::Locale;
 use crate::wallet;
 
 #[component]
 pub fn StartPage() -> impl IntoView {
     let wallet_state = expect_context::<RwSignal<WalletState>>();
+    let locale = expect_context::<RwSignal<Locale>>();
     let navigate = use_navigate();
     let loading = RwSignal::new(false);
 
@@ -42,9 +44,12 @@ pub fn StartPage() -> impl IntoView {
     let status_text = move || {
         let ws = wallet_state.get();
         if ws.connected {
-            format!("Wallet connected: {}", ws.pubkey.unwrap_or_default())
+            locale.get().format(
+                "start.wallet_connected",
+                &[("pubkey", ws.pubkey.unwrap_or_default().into())],
+            )
         } else {
-            "Wallet not connected".to_string()
+            locale.get().t("start.wallet_not_connected")
         }
     };
 
@@ -81,7 +86,7 @@ pub fn StartPage() -> impl IntoView {
                 on:click=on_start
                 disabled=move || loading.get()
             >
-                {move || if loading.get() { "CONNECTING..." } else { "START GAME" }}
+                {move || locale.get().t(if loading.get() { "start.connecting" } else { "start.start" })}
             </button>
             <p class={status_class}>{status_text}</p>
         </div>
@@ -0,0 +1,28 @@
+use crate::game_state::EnemyTemplate;
+
+pub const MAX_LEVEL: u32 = 10;
+
+/// Stat gains per level above 1.
+pub const HP_PER_LEVEL: u32 = 6;
+pub const ATK_PER_LEVEL: u32 = 1;
+pub const DEF_PER_LEVEL: u32 = 1;
+/// One point of speed every this many levels.
+pub const LEVELS_PER_SPEED: u32 = 3;
+
+/// Total XP needed to reach `level` (100, 300, 600, ...).
+pub fn xp_for_level(level: u32) -> u32 {
+    let l = level.clamp(1, MAX_LEVEL) - 1;
+    50 * l * (l + 1)
+}
+
+pub fn level_for_xp(xp: u32) -> u32 {
+    (1..=MAX_LEVEL)
+        .rev()
+        .find(|&l| xp >= xp_for_level(l))
+        .unwrap_or(1)
+}
+
+/// XP for beating `enemy`; tougher enemies are worth more.
+pub fn victory_xp(enemy: &EnemyTemplate) -> u32 {
+    enemy.hp / 4 + (enemy.atk + enemy.def) * 2
+}
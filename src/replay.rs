@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_state::{
+    Action, BattleResult, BattleState, CharacterTemplate, EnemyTemplate, PlayerState,
+};
+use crate::items::Inventory;
+use crate::wallet;
+
+/// localStorage key holding the most recent replays (newest first).
+const STORAGE_KEY: &str = "mojo_replays";
+const MAX_SAVED: usize = 10;
+
+/// Everything needed to re-run a battle bit-for-bit: the starting parties,
+/// their items, the RNG seed and every human-made action. AI
+/// moves are left out; the AI makes them again on re-simulation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BattleReplay {
+    pub seed: u64,
+    /// Lead of the player's party.
+    pub character: CharacterTemplate,
+    /// The rest of the player's party.
+    #[serde(default)]
+    pub party: Vec<CharacterTemplate>,
+    #[serde(default)]
+    pub enemy: EnemyTemplate,
+    /// The rest of the enemy party.
+    #[serde(default)]
+    pub foes: Vec<EnemyTemplate>,
+    /// Items the player carried in.
+    #[serde(default)]
+    pub items: Inventory,
+    /// Player HP at the start, when it wasn't full (campaign fights).
+    #[serde(default)]
+    pub start_hp: Option<i32>,
+    /// Second player's character in a hot-seat battle (None against the AI).
+    #[serde(default)]
+    pub rival: Option<CharacterTemplate>,
+    /// Actions of the human side(s), in the order they were taken.
+    pub actions: Vec<Action>,
+}
+
+/// Final state reached by re-simulating a replay.
+#[derive(Clone, Debug)]
+pub struct ReplayOutcome {
+    pub result: Option<BattleResult>,
+    pub score: u32,
+    pub battle: BattleState,
+    /// Every recorded action was accepted and the battle ended on the last
+    /// one: a finished, legal game.
+    pub complete: bool,
+}
+
+impl BattleReplay {
+    /// Capture the replay of a battle started from `party` (leader first).
+    /// `rival` is the second player's character in a hot-seat battle.
+    pub fn from_battle(
+        party: &[CharacterTemplate],
+        rival: Option<&CharacterTemplate>,
+        battle: &BattleState,
+    ) -> Self {
+        let max_hp = battle.player().max_hp;
+        Self {
+            seed: battle.seed,
+            character: party[0].clone(),
+            party: party[1..].to_vec(),
+            enemy: battle.enemy().clone(),
+            foes: battle.starting_enemies()[1..].to_vec(),
+            items: battle.starting_inventory.clone(),
+            start_hp: (battle.starting_hp < max_hp).then_some(battle.starting_hp),
+            rival: rival.cloned(),
+            actions: battle
+                .actions
+                .iter()
+                .filter(|(side, _)| battle.side(*side).is_human())
+                .map(|(_, action)| *action)
+                .collect(),
+        }
+    }
+
+    /// Fresh battle ready for the first human move (a faster enemy has
+    /// already opened), to be stepped through.
+    pub fn start(&self) -> BattleState {
+        let mut battle = match &self.rival {
+            Some(rival) => BattleState::hot_seat(&self.character, rival, self.seed),
+            None => BattleState::team_battle(&self.party(), &self.enemies(), self.seed),
+        }
+        .with_inventory(self.items.clone());
+        if let Some(hp) = self.start_hp {
+            battle = battle.with_player_hp(hp);
+        }
+        enemy_phase(&mut battle);
+        battle
+    }
+
+    /// The player's whole party, leader first.
+    pub fn party(&self) -> Vec<CharacterTemplate> {
+        std::iter::once(&self.character)
+            .chain(&self.party)
+            .cloned()
+            .collect()
+    }
+
+    /// The whole enemy party, leader first.
+    pub fn enemies(&self) -> Vec<EnemyTemplate> {
+        std::iter::once(&self.enemy)
+            .chain(&self.foes)
+            .cloned()
+            .collect()
+    }
+
+    /// Check that a saved on-chain `PlayerState` is exactly what this
+    /// sequence of moves produces, and that the moves are a legal, finished
+    /// game with nothing after its end.
+    pub fn verify(&self, claimed: &PlayerState) -> bool {
+        let outcome = replay(self);
+        outcome.complete && PlayerState::from_battle(&outcome.battle) == *claimed
+    }
+}
+
+/// Apply one human action followed by any AI turns, exactly as
+/// `GameSessionPage` does. Returns false if the battle was already over
+/// (or the action was illegal, e.g. an ability still on cooldown).
+pub fn step(battle: &mut BattleState, action: Action) -> bool {
+    if !battle.act(action) {
+        return false;
+    }
+    enemy_phase(battle);
+    true
+}
+
+/// Let the AI act until a person is up again. The AI gets several turns
+/// in a row when the player is stunned or slower.
+pub fn enemy_phase(battle: &mut BattleState) {
+    while battle.ai_turn().is_some() {}
+}
+
+/// Re-simulate a recorded battle through `BattleState`, stopping at the
+/// first action it rejects (including any after the battle ended).
+pub fn replay(rec: &BattleReplay) -> ReplayOutcome {
+    let mut battle = rec.start();
+    let mut applied = 0;
+    for &action in &rec.actions {
+        if !step(&mut battle, action) {
+            break;
+        }
+        applied += 1;
+    }
+    ReplayOutcome {
+        result: battle.result.clone(),
+        score: battle.score,
+        complete: applied == rec.actions.len() && battle.result.is_some(),
+        battle,
+    }
+}
+
+/// Saved replays, newest first.
+pub fn load_saved() -> Vec<BattleReplay> {
+    wallet::load_from_storage(STORAGE_KEY)
+        .and_then(|s| serde_json::from_str::<Vec<BattleReplay>>(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Prepend a replay to the saved list, keeping only the most recent few.
+pub fn save(rec: BattleReplay) {
+    let mut all = load_saved();
+    all.insert(0, rec);
+    all.truncate(MAX_SAVED);
+    if let Ok(json) = serde_json::to_string(&all) {
+        wallet::save_to_storage(STORAGE_KEY, &json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A finished battle between the first god and the first enemy, played
+    /// the way the battle page plays it, and its replay.
+    fn played(seed: u64) -> (BattleState, BattleReplay) {
+        let party = [CharacterTemplate::all().remove(0)];
+        let mut battle = BattleState::team_battle(&party, &[EnemyTemplate::all().remove(0)], seed);
+        enemy_phase(&mut battle);
+        let mut turn = 0;
+        while battle.result.is_none() {
+            let action = if turn % 4 == 3 {
+                Action::Defend
+            } else {
+                Action::Attack
+            };
+            assert!(step(&mut battle, action));
+            turn += 1;
+        }
+        let rec = BattleReplay::from_battle(&party, None, &battle);
+        (battle, rec)
+    }
+
+    #[test]
+    fn replay_reproduces_the_battle() {
+        let (battle, rec) = played(21);
+        let outcome = replay(&rec);
+        assert_eq!(outcome.result, battle.result);
+        assert_eq!(outcome.score, battle.score);
+        assert_eq!(outcome.battle.events, battle.events);
+        assert!(rec.verify(&PlayerState::from_battle(&battle)));
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_score() {
+        let (battle, rec) = played(21);
+        let mut claimed = PlayerState::from_battle(&battle);
+        claimed.score += 1;
+        assert!(!rec.verify(&claimed));
+    }
+
+    #[test]
+    fn verify_rejects_tampered_actions() {
+        let (battle, mut rec) = played(21);
+        let claimed = PlayerState::from_battle(&battle);
+        rec.actions[0] = Action::Defend;
+        assert!(!rec.verify(&claimed));
+
+        let (_, mut rec) = played(21);
+        rec.actions.truncate(rec.actions.len() / 2);
+        assert!(!rec.verify(&claimed));
+    }
+
+    #[test]
+    fn verify_rejects_actions_after_the_end() {
+        let (battle, mut rec) = played(21);
+        let claimed = PlayerState::from_battle(&battle);
+        rec.actions.push(Action::Attack);
+        assert!(!rec.verify(&claimed));
+    }
+
+    #[test]
+    fn verify_rejects_an_illegal_action() {
+        let (battle, mut rec) = played(21);
+        let claimed = PlayerState::from_battle(&battle);
+        // Nobody is on the enemy's field at slot 9
+        rec.actions.insert(0, Action::Target(9));
+        assert!(!rec.verify(&claimed));
+    }
+}
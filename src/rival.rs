@@ -0,0 +1,150 @@
+use bytemuck::Zeroable;
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use crate::affinity::Affinity;
+use crate::game_state::{EnemyBehaviour, EnemyTemplate, PlayerState};
+use crate::initiative;
+use crate::locale::Locale;
+use crate::pages::character_select::bs58_decode;
+use crate::{solana_bridge, wallet};
+
+/// State name every wallet's battle snapshot is written under.
+pub const STATE_NAME: &str = "mojo_battle";
+
+/// First seed of a world state account; the owner and the state name
+/// follow it.
+const STATE_SEED: &[u8] = b"state";
+
+/// localStorage key holding results against other wallets (newest first).
+const HISTORY_KEY: &str = "mojo_rival_results";
+const MAX_HISTORY: usize = 20;
+
+/// Another wallet's snapshot, ready to be fought.
+#[derive(Clone, Debug)]
+pub struct Rival {
+    pub pubkey: String,
+    pub enemy: EnemyTemplate,
+    /// Also write the result to our own state account.
+    pub post_result: bool,
+}
+
+/// One finished fight against a rival.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RivalResult {
+    pub pubkey: String,
+    pub victory: bool,
+    pub score: u32,
+}
+
+pub fn parse_pubkey(input: &str, locale: Locale) -> Result<Pubkey, String> {
+    let bytes: [u8; 32] = bs58_decode(input.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| locale.t("rivals.error.invalid_pubkey"))?;
+    Ok(Pubkey::new_from_array(bytes))
+}
+
+/// Address of `owner`'s battle state account: the world program's PDA for
+/// the state seeds. The SDK only exposes the program id on the
+/// instructions it builds, so it's read off a write transaction, and the
+/// derived address has to be one that transaction touches.
+fn state_account(owner: Pubkey, locale: Locale) -> Result<Pubkey, String> {
+    let bundle = mojo_rust_sdk::world::World::build_write_state_tx(
+        owner,
+        STATE_NAME,
+        &PlayerState::zeroed().serialize_state(),
+    )
+    .map_err(|e| locale.format("tx.build_state_failed", &[("error", e.to_string().into())]))?;
+    let seeds: [&[u8]; 3] = [STATE_SEED, owner.as_ref(), STATE_NAME.as_bytes()];
+    bundle
+        .instructions
+        .iter()
+        .find_map(|ix| {
+            let (address, _) = Pubkey::find_program_address(&seeds, &ix.program_id);
+            ix.accounts
+                .iter()
+                .any(|acc| acc.pubkey == address)
+                .then_some(address)
+        })
+        .ok_or_else(|| locale.t("rivals.error.no_state_account"))
+}
+
+/// The first and last four characters of an address, or all of it when
+/// it's too short to shorten.
+fn short_address(owner: &str) -> String {
+    let chars: Vec<char> = owner.chars().collect();
+    if chars.len() <= 8 {
+        return owner.to_string();
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+/// Read and decode `owner`'s latest battle snapshot from chain.
+pub async fn fetch_snapshot(owner: Pubkey, locale: Locale) -> Result<PlayerState, String> {
+    let address = state_account(owner, locale)?;
+    let data = solana_bridge::fetch_account_data(&address)
+        .await?
+        .ok_or_else(|| locale.t("rivals.error.no_snapshot"))?;
+    PlayerState::from_account_data(&data).ok_or_else(|| locale.t("rivals.error.bad_snapshot"))
+}
+
+/// An AI opponent fighting with a snapshot's stats, at full health.
+pub fn opponent(owner: &str, state: &PlayerState, locale: Locale) -> EnemyTemplate {
+    let short = short_address(owner);
+    // Stable colour per wallet
+    let hue = owner
+        .bytes()
+        .fold(0u32, |h, b| h.wrapping_mul(31).wrapping_add(b as u32))
+        % 360;
+    EnemyTemplate {
+        name: locale.format("rivals.name", &[("address", short.into())]),
+        hp: (state.max_health as u32).max(1),
+        atk: state.attack as u32,
+        def: state.defense as u32,
+        speed: initiative::DEFAULT_SPEED,
+        hue,
+        // A snapshot only carries stats
+        affinity: Affinity::Neutral,
+        behaviour: if state.attack >= state.defense {
+            EnemyBehaviour::Aggressive
+        } else {
+            EnemyBehaviour::Cautious
+        },
+        description: locale.format("rivals.description", &[("score", state.score.into())]),
+        on_hit: None,
+        abilities: vec![],
+        phases: vec![],
+    }
+}
+
+/// Results against rivals, newest first.
+pub fn load_history() -> Vec<RivalResult> {
+    wallet::load_from_storage(HISTORY_KEY)
+        .and_then(|s| serde_json::from_str::<Vec<RivalResult>>(&s).ok())
+        .unwrap_or_default()
+}
+
+pub fn record(result: RivalResult) {
+    let mut all = load_history();
+    all.insert(0, result);
+    all.truncate(MAX_HISTORY);
+    if let Ok(json) = serde_json::to_string(&all) {
+        wallet::save_to_storage(HISTORY_KEY, &json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_address_never_splits_a_character() {
+        assert_eq!(short_address("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin"), "9xQe…VFin");
+        assert_eq!(short_address("abc"), "abc");
+        assert_eq!(short_address(""), "");
+        assert_eq!(short_address("ééééééééé"), "éééé…éééé");
+    }
+}
@@ -0,0 +1,162 @@
+use crate::battle_event::BattleEvent;
+use crate::game_state::{BattleResult, Combatant, Turn};
+
+/// Flat bonus for winning.
+pub const VICTORY_BONUS: u32 = 100;
+
+/// Bonus per percent of the party's max HP left after a victory.
+pub const HP_BONUS_PER_PERCENT: u32 = 1;
+
+/// Victories in fewer turns than this earn a speed bonus.
+pub const PAR_TURNS: u32 = 15;
+pub const SPEED_BONUS_PER_TURN: u32 = 10;
+
+/// Damage avoided counts for this percent of its value.
+pub const AVOIDED_PERCENT: u32 = 50;
+
+/// Each combo raises the score multiplier by this many percent, up to
+/// `MAX_COMBO_PERCENT`.
+pub const COMBO_SCORE_PERCENT: u32 = 2;
+const MAX_COMBO_PERCENT: u32 = 20;
+
+/// Bounds of the difficulty multiplier, in percent.
+const MIN_DIFFICULTY: u32 = 50;
+const MAX_DIFFICULTY: u32 = 200;
+
+/// How a battle's score is made up, from the first side's point of view.
+/// `total()` is the `score` written on-chain.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScoreBreakdown {
+    /// Damage dealt to the enemy, including damage over time.
+    pub damage_dealt: u32,
+    /// Damage that never landed: dodged, evaded, blocked or absorbed by
+    /// a shield.
+    pub damage_avoided: u32,
+    /// Turns the player's side has taken.
+    pub turns: u32,
+    /// Party HP left, as a percentage of its max HP.
+    pub hp_remaining_percent: u32,
+    pub victory: bool,
+    /// Multiplier (percent) for how strong the enemies were relative to
+    /// the party.
+    pub difficulty_percent: u32,
+    /// Combos the player's side finished (see `combo::ComboKind`).
+    pub combos: u32,
+}
+
+impl ScoreBreakdown {
+    pub fn new(difficulty_percent: u32) -> Self {
+        Self {
+            difficulty_percent,
+            ..Self::default()
+        }
+    }
+
+    /// Count what `event` adds to the damage dealt or avoided, or whether
+    /// it won the battle.
+    pub fn record(&mut self, event: &BattleEvent) {
+        match *event {
+            BattleEvent::DamageDealt { source, amount, .. } if source.side == Turn::Player => {
+                self.damage_dealt += amount;
+            }
+            BattleEvent::Blocked {
+                source,
+                target,
+                amount,
+                avoided,
+                ..
+            } => {
+                if source.side == Turn::Player {
+                    self.damage_dealt += amount;
+                }
+                if target.side == Turn::Player {
+                    self.damage_avoided += avoided;
+                }
+            }
+            BattleEvent::Missed {
+                target, avoided, ..
+            } if target.side == Turn::Player => {
+                self.damage_avoided += avoided;
+            }
+            // The hit was counted in full when it landed
+            BattleEvent::ShieldAbsorbed { target, amount } => match target.side {
+                Turn::Player => self.damage_avoided += amount,
+                Turn::Enemy => self.damage_dealt = self.damage_dealt.saturating_sub(amount),
+            },
+            BattleEvent::Thorns { source, amount, .. } if source.side == Turn::Player => {
+                self.damage_dealt += amount;
+            }
+            BattleEvent::StatusDamage { target, amount, .. } if target.side == Turn::Enemy => {
+                self.damage_dealt += amount;
+            }
+            BattleEvent::Combo { id, .. } if id.side == Turn::Player => {
+                self.combos += 1;
+            }
+            BattleEvent::BattleEnded { ref result } => {
+                self.victory = *result == BattleResult::Victory;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn avoided_points(&self) -> u32 {
+        self.damage_avoided * AVOIDED_PERCENT / 100
+    }
+
+    pub fn victory_bonus(&self) -> u32 {
+        if self.victory {
+            VICTORY_BONUS
+        } else {
+            0
+        }
+    }
+
+    pub fn hp_bonus(&self) -> u32 {
+        if self.victory {
+            self.hp_remaining_percent * HP_BONUS_PER_PERCENT
+        } else {
+            0
+        }
+    }
+
+    /// Rewards a quick win rather than a long grind.
+    pub fn speed_bonus(&self) -> u32 {
+        if self.victory {
+            PAR_TURNS.saturating_sub(self.turns) * SPEED_BONUS_PER_TURN
+        } else {
+            0
+        }
+    }
+
+    /// Everything before the difficulty multiplier.
+    pub fn subtotal(&self) -> u32 {
+        self.damage_dealt
+            + self.avoided_points()
+            + self.victory_bonus()
+            + self.hp_bonus()
+            + self.speed_bonus()
+    }
+
+    /// Multiplier (percent) earned by playing combos.
+    pub fn combo_percent(&self) -> u32 {
+        100 + (self.combos * COMBO_SCORE_PERCENT).min(MAX_COMBO_PERCENT)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.subtotal() * self.difficulty_percent / 100 * self.combo_percent() / 100
+    }
+}
+
+/// Rough fighting strength: HP plus weighted ATK and DEF.
+fn power(fighters: &[Combatant]) -> u32 {
+    fighters
+        .iter()
+        .map(|c| c.max_hp.max(0) as u32 + 4 * (c.atk + c.def))
+        .sum()
+}
+
+/// Enemy strength relative to the party's, in percent, clamped to
+/// `MIN_DIFFICULTY..=MAX_DIFFICULTY`.
+pub fn difficulty_percent(party: &[Combatant], enemies: &[Combatant]) -> u32 {
+    (power(enemies) * 100 / power(party).max(1)).clamp(MIN_DIFFICULTY, MAX_DIFFICULTY)
+}
@@ -1,7 +1,8 @@
 use mojo_rust_sdk::transaction::TransactionBundle;
 use serde::Serialize;
+use solana_pubkey::Pubkey;
 
-use crate::wallet::js_build_and_send_tx;
+use crate::wallet::{js_build_and_send_tx, js_get_account_data};
 
 #[derive(Serialize)]
 struct SerializedAccount {
@@ -59,3 +60,14 @@ pub async fn send_transaction_bundle(bundle: TransactionBundle) -> Result<String
         .as_string()
         .ok_or_else(|| "No signature returned".to_string())
 }
+
+/// Raw data of the account at `address`, or None if it doesn't exist
+pub async fn fetch_account_data(address: &Pubkey) -> Result<Option<Vec<u8>>, String> {
+    let value = js_get_account_data(&address.to_bytes())
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    if value.is_null() || value.is_undefined() {
+        return Ok(None);
+    }
+    Ok(Some(js_sys::Uint8Array::new(&value).to_vec()))
+}
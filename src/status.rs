@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::locale::Locale;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// Lose `potency` HP at the start of each turn, growing by 1 per tick.
+    Poison,
+    /// Skip your next turn(s).
+    Stun,
+    /// Lose `potency` HP at the start of each turn.
+    Burn,
+    /// Absorb up to `potency` direct damage before HP is touched.
+    Shield,
+    /// Recover `potency` HP at the start of each turn.
+    Regen,
+}
+
+impl StatusKind {
+    pub fn label(self, locale: Locale) -> String {
+        locale.t(match self {
+            StatusKind::Poison => "status.poison",
+            StatusKind::Stun => "status.stun",
+            StatusKind::Burn => "status.burn",
+            StatusKind::Shield => "status.shield",
+            StatusKind::Regen => "status.regen",
+        })
+    }
+}
+
+/// A timed effect attached to a combatant.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    /// Turn boundaries left before the effect expires.
+    pub turns: u32,
+    pub potency: u32,
+}
+
+/// What happened to one combatant when their statuses ticked.
+#[derive(Clone, Debug, Default)]
+pub struct TickReport {
+    pub damage: Vec<(StatusKind, u32)>,
+    pub heal: u32,
+    pub stunned: bool,
+    pub expired: Vec<StatusKind>,
+}
+
+/// Attach an effect. Re-applying a kind that is already active refreshes it,
+/// keeping the longer duration and the stronger potency.
+pub fn apply(effects: &mut Vec<StatusEffect>, effect: StatusEffect) {
+    if let Some(existing) = effects.iter_mut().find(|e| e.kind == effect.kind) {
+        existing.turns = existing.turns.max(effect.turns);
+        existing.potency = existing.potency.max(effect.potency);
+    } else {
+        effects.push(effect);
+    }
+}
+
+/// Run one turn boundary: collect damage-over-time, healing and stun, count
+/// every effect down by one and drop the ones that ran out.
+pub fn tick(effects: &mut Vec<StatusEffect>) -> TickReport {
+    let mut report = TickReport::default();
+    for effect in effects.iter_mut() {
+        match effect.kind {
+            StatusKind::Poison => {
+                report.damage.push((StatusKind::Poison, effect.potency));
+                effect.potency += 1;
+            }
+            StatusKind::Burn => report.damage.push((StatusKind::Burn, effect.potency)),
+            StatusKind::Regen => report.heal += effect.potency,
+            StatusKind::Stun => report.stunned = true,
+            StatusKind::Shield => {}
+        }
+        effect.turns = effect.turns.saturating_sub(1);
+    }
+    effects.retain(|e| {
+        if e.turns == 0 {
+            report.expired.push(e.kind);
+        }
+        e.turns > 0
+    });
+    report
+}
+
+/// Soak `damage` with any active shield. Returns the damage that gets through.
+pub fn absorb(effects: &mut Vec<StatusEffect>, damage: u32) -> u32 {
+    let Some(pos) = effects.iter().position(|e| e.kind == StatusKind::Shield) else {
+        return damage;
+    };
+    let shield = &mut effects[pos];
+    let soaked = shield.potency.min(damage);
+    shield.potency -= soaked;
+    if shield.potency == 0 {
+        effects.remove(pos);
+    }
+    damage - soaked
+}
@@ -31,8 +31,7 @@ const BG: &str = "rgb(10,10,10)";
 
 /// Generate a full colored stick-figure SVG for a character (client-side only).
 /// Used on the profile page to render owned characters — NOT sent on-chain.
-pub fn generate_character_svg(index: usize, name: &str) -> String {
-    let hue = index * 60;
+pub fn generate_character_svg(hue: u32, name: &str) -> String {
     let color = format!("hsl({},80%,60%)", hue);
     format!(
         "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"120\" height=\"160\" viewBox=\"0 0 120 160\">\
@@ -54,8 +53,7 @@ pub fn generate_character_svg(index: usize, name: &str) -> String {
 /// Build a tiny on-chain metadata URI for a character NFT.
 /// Keeps it small (~120 bytes) to fit within Solana tx limits.
 /// The image is a minimal colored-circle SVG with the character's initial.
-pub fn build_character_metadata_uri(index: usize, name: &str, _description: &str) -> String {
-    let hue = index * 60;
+pub fn build_character_metadata_uri(hue: u32, name: &str, _description: &str) -> String {
     let initial = name.chars().next().unwrap_or('?');
     // Tiny SVG: colored circle + initial letter (~120 bytes)
     let svg = format!(
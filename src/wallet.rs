@@ -69,16 +69,15 @@ pub async fn upload_bytes_to_irys(data: &[u8], content_type: &str) -> Result<Str
 /// Generates a PNG of the stick figure on an HTML canvas, uploads it to get
 /// a real https://gateway.irys.xyz/<id> image URL, builds the metadata JSON
 /// referencing that URL, uploads the JSON, and returns the metadata URL.
-/// char_index drives the character colour (hue = index * 60 degrees).
+/// hue is the character's colour in degrees.
 pub async fn upload_character_metadata(
-    char_index: usize,
+    hue: u32,
     name: &str,
     description: &str,
     hp: u32,
     atk: u32,
     def: u32,
 ) -> Result<String, String> {
-    let hue = (char_index * 60) as u32;
     js_upload_character_metadata(hue, name, description, hp, atk, def)
         .await
         .map_err(|e| format!("Upload error: {:?}", e))?
@@ -300,6 +299,10 @@ export function js_build_and_send_tx(instructions_json, signers_json) {
     return window.solanaBridge.buildAndSendTransaction(instructions_json, signers_json);
 }
 
+export function js_get_account_data(address) {
+    return window.solanaBridge.getAccountData(address);
+}
+
 export function js_save_to_storage(key, value) {
     try { localStorage.setItem(key, value); } catch(e) {}
 }
@@ -325,6 +328,9 @@ extern "C" {
         signers_json: &str,
     ) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(catch)]
+    pub async fn js_get_account_data(address: &[u8]) -> Result<JsValue, JsValue>;
+
     fn js_save_to_storage(key: &str, value: &str);
 
     fn js_load_from_storage(key: &str) -> JsValue;